@@ -139,6 +139,9 @@ impl Auth {
     }
 
     /// Get the access token for a security code. [required: apikey]
+    ///
+    /// Fails with an error for which [`Error::is_invalid_code`](crate::Error::is_invalid_code)
+    /// returns true if the code is invalid, expired or has already been redeemed.
     pub async fn security_code(self, code: &str) -> Result<Credentials> {
         let t = self
             .modio
@@ -157,10 +160,17 @@ impl Auth {
         })
     }
 
-    /// Authenticate via external services ([Steam], [Switch], [Xbox], [Discord], [Oculus], [Google]).
+    /// Authenticate via external services ([Steam], [Switch], [Xbox], [Discord], [Oculus], [Google],
+    /// [Apple]).
     ///
     /// See the [mod.io docs](https://docs.mod.io/#authentication-2) for more information.
     ///
+    /// The [terms](Auth::terms) must be shown to the user before the first authentication and
+    /// accepted with the `terms_agreed` option, otherwise the request fails with an error for
+    /// which [`Error::is_terms_acceptance_required`](crate::Error::is_terms_acceptance_required)
+    /// returns true.
+    ///
+    /// [Apple]: AppleOptions
     /// [Steam]: SteamOptions
     /// [Oculus]: OculusOptions
     /// [Switch]: SwitchOptions
@@ -205,13 +215,27 @@ impl Auth {
     }
 
     /// Logout by revoking the current access token.
-    pub async fn logout(self) -> Result<()> {
+    ///
+    /// Returns an endpoint [without the token](Modio::without_token) so the revoked token isn't
+    /// used again. The returned endpoint can be discarded if the client isn't used anymore.
+    ///
+    /// mod.io revokes only the token of the request, tokens of other devices stay valid.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn run() -> modio::Result<()> {
+    /// #   let modio = modio::Modio::new(("api-key", "token"))?;
+    /// let modio = modio.auth().logout().await?;
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub async fn logout(self) -> Result<Modio> {
         self.modio
             .request(Route::OAuthLogout)
             .send::<Message>()
             .await?;
 
-        Ok(())
+        Ok(self.modio.without_token())
     }
 }
 
@@ -222,6 +246,15 @@ pub struct AuthOptions {
 }
 
 // impl From<*Options> for AuthOptions {{{
+impl From<AppleOptions> for AuthOptions {
+    fn from(options: AppleOptions) -> AuthOptions {
+        AuthOptions {
+            route: Route::ExternalAuthApple,
+            params: options.params,
+        }
+    }
+}
+
 impl From<OculusOptions> for AuthOptions {
     fn from(options: OculusOptions) -> AuthOptions {
         AuthOptions {
@@ -277,6 +310,35 @@ impl From<GoogleOptions> for AuthOptions {
 }
 // }}}
 
+/// Authentication options for an Apple ID token.
+///
+/// See the [mod.io docs](https://docs.mod.io/#apple) for more information.
+pub struct AppleOptions {
+    params: BTreeMap<&'static str, String>,
+}
+
+impl AppleOptions {
+    pub fn new<T>(token: T) -> Self
+    where
+        T: Into<String>,
+    {
+        let mut params = BTreeMap::new();
+        params.insert("id_token", token.into());
+        Self { params }
+    }
+
+    option!(email >> "email");
+    option!(
+        /// Unix timestamp of date in which the returned token will expire. Value cannot be higher
+        /// than the default value which is a week.
+        expired_at u64 >> "date_expires"
+    );
+    option!(
+        /// Set to `true` once the user has agreed to the mod.io terms, see [`Auth::external`].
+        terms_agreed bool >> "terms_agreed"
+    );
+}
+
 /// Authentication options for an encrypted gog app ticket.
 ///
 /// See the [mod.io docs](https://docs.mod.io/#gog-galaxy) for more information.
@@ -300,7 +362,10 @@ impl GalaxyOptions {
         /// than the default value which is a common year.
         expired_at u64 >> "date_expires"
     );
-    option!(terms_agreed bool >> "terms_agreed");
+    option!(
+        /// Set to `true` once the user has agreed to the mod.io terms, see [`Auth::external`].
+        terms_agreed bool >> "terms_agreed"
+    );
 }
 
 /// Authentication options for an itch.io JWT token.
@@ -326,7 +391,10 @@ impl ItchioOptions {
         /// than the default value which is a week.
         expired_at u64 >> "date_expires"
     );
-    option!(terms_agreed bool >> "terms_agreed");
+    option!(
+        /// Set to `true` once the user has agreed to the mod.io terms, see [`Auth::external`].
+        terms_agreed bool >> "terms_agreed"
+    );
 }
 
 /// Authentication options for an Oculus user.
@@ -366,7 +434,10 @@ impl OculusOptions {
         /// than the default value which is a common year.
         expired_at u64 >> "date_expires"
     );
-    option!(terms_agreed bool >> "terms_agreed");
+    option!(
+        /// Set to `true` once the user has agreed to the mod.io terms, see [`Auth::external`].
+        terms_agreed bool >> "terms_agreed"
+    );
 }
 
 /// Authentication options for an encrypted steam app ticket.
@@ -392,7 +463,10 @@ impl SteamOptions {
         /// than the default value which is a common year.
         expired_at u64 >> "date_expires"
     );
-    option!(terms_agreed bool >> "terms_agreed");
+    option!(
+        /// Set to `true` once the user has agreed to the mod.io terms, see [`Auth::external`].
+        terms_agreed bool >> "terms_agreed"
+    );
 }
 
 /// Authentication options for the NSA ID token.
@@ -418,7 +492,10 @@ impl SwitchOptions {
         /// than the default value which is a common year.
         expired_at u64 >> "date_expires"
     );
-    option!(terms_agreed bool >> "terms_agreed");
+    option!(
+        /// Set to `true` once the user has agreed to the mod.io terms, see [`Auth::external`].
+        terms_agreed bool >> "terms_agreed"
+    );
 }
 
 /// Authentication options for an Xbox Live token.
@@ -444,7 +521,10 @@ impl XboxOptions {
         /// than the default value which is a common year.
         expired_at u64 >> "date_expires"
     );
-    option!(terms_agreed bool >> "terms_agreed");
+    option!(
+        /// Set to `true` once the user has agreed to the mod.io terms, see [`Auth::external`].
+        terms_agreed bool >> "terms_agreed"
+    );
 }
 
 /// Authentication options for an Discord token.
@@ -470,7 +550,10 @@ impl DiscordOptions {
         /// than the default value which is a week.
         expired_at u64 >> "date_expires"
     );
-    option!(terms_agreed bool >> "terms_agreed");
+    option!(
+        /// Set to `true` once the user has agreed to the mod.io terms, see [`Auth::external`].
+        terms_agreed bool >> "terms_agreed"
+    );
 }
 
 /// Authentication options for an Google token.
@@ -490,13 +573,73 @@ impl GoogleOptions {
         Self { params }
     }
 
+    /// Authenticate with the server auth code of Google Play Games instead of an ID token.
+    pub fn play_games<T>(auth_code: T) -> Self
+    where
+        T: Into<String>,
+    {
+        let mut params = BTreeMap::new();
+        params.insert("auth_code", auth_code.into());
+        Self { params }
+    }
+
     option!(email >> "email");
     option!(
         /// Unix timestamp of date in which the returned token will expire. Value cannot be higher
         /// than the default value which is a week.
         expired_at u64 >> "date_expires"
     );
-    option!(terms_agreed bool >> "terms_agreed");
+    option!(
+        /// Set to `true` once the user has agreed to the mod.io terms, see [`Auth::external`].
+        terms_agreed bool >> "terms_agreed"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AppleOptions, AuthOptions, GoogleOptions, SteamOptions};
+    use crate::routing::Route;
+
+    #[test]
+    fn terms_agreed_param() {
+        let opts = AuthOptions::from(SteamOptions::new("ticket").terms_agreed(true));
+
+        assert_eq!(
+            opts.params.get("appdata").map(String::as_str),
+            Some("ticket")
+        );
+        assert_eq!(
+            opts.params.get("terms_agreed").map(String::as_str),
+            Some("true")
+        );
+    }
+
+    #[test]
+    fn apple_options() {
+        let opts = AuthOptions::from(AppleOptions::new("token").email("user@example.com"));
+
+        assert!(matches!(opts.route, Route::ExternalAuthApple));
+        assert_eq!(
+            opts.params.get("id_token").map(String::as_str),
+            Some("token")
+        );
+        assert_eq!(
+            opts.params.get("email").map(String::as_str),
+            Some("user@example.com")
+        );
+    }
+
+    #[test]
+    fn google_play_games_options() {
+        let opts = AuthOptions::from(GoogleOptions::play_games("code"));
+
+        assert!(matches!(opts.route, Route::ExternalAuthGoogle));
+        assert_eq!(
+            opts.params.get("auth_code").map(String::as_str),
+            Some("code")
+        );
+        assert!(!opts.params.contains_key("id_token"));
+    }
 }
 
 // vim: fdm=marker
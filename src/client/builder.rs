@@ -1,14 +1,17 @@
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use http::header::USER_AGENT;
 use http::header::{HeaderMap, HeaderValue};
+#[cfg(feature = "__tls")]
+use reqwest::Certificate;
 use reqwest::{Client, ClientBuilder, Proxy};
+use url::Url;
 
 use crate::auth::Credentials;
 use crate::error::{self, Error, Result};
 use crate::{TargetPlatform, TargetPortal};
 
-use super::{ClientRef, Modio};
+use super::{ClientRef, Modio, RateLimiter};
 use super::{DEFAULT_AGENT, DEFAULT_HOST, TEST_HOST};
 
 /// A `Builder` can be used to create a `Modio` client with custom configuration.
@@ -23,8 +26,12 @@ struct Config {
     builder: Option<ClientBuilder>,
     headers: HeaderMap,
     proxies: Vec<Proxy>,
+    default_limit: Option<usize>,
+    max_requests_per_minute: Option<u32>,
     #[cfg(feature = "__tls")]
     tls: TlsBackend,
+    #[cfg(feature = "__tls")]
+    root_certs: Vec<Certificate>,
     error: Option<Error>,
 }
 
@@ -63,8 +70,12 @@ impl Builder {
                 builder: None,
                 headers: HeaderMap::new(),
                 proxies: Vec::new(),
+                default_limit: None,
+                max_requests_per_minute: None,
                 #[cfg(feature = "__tls")]
                 tls: TlsBackend::default(),
+                #[cfg(feature = "__tls")]
+                root_certs: Vec::new(),
                 error: None,
             },
         }
@@ -80,6 +91,9 @@ impl Builder {
 
         let host = config.host.unwrap_or_else(|| DEFAULT_HOST.to_string());
         let credentials = config.credentials;
+        let rate_limiter = config
+            .max_requests_per_minute
+            .map(|n| Arc::new(RateLimiter::per_minute(n)));
 
         let client = {
             let mut builder = {
@@ -105,6 +119,11 @@ impl Builder {
                 builder = builder.proxy(proxy);
             }
 
+            #[cfg(feature = "__tls")]
+            for cert in config.root_certs {
+                builder = builder.add_root_certificate(cert);
+            }
+
             builder
                 .default_headers(headers)
                 .build()
@@ -116,6 +135,10 @@ impl Builder {
                 host,
                 client,
                 credentials,
+                default_limit: config.default_limit,
+                rate_limiter,
+                rate_limit: Mutex::new(None),
+                current_user: Mutex::new(None),
             }),
         })
     }
@@ -132,8 +155,23 @@ impl Builder {
     /// Set the mod.io api host.
     ///
     /// Defaults to `"https://api.mod.io/v1"`
+    ///
+    /// The host must be an absolute `http` or `https` url, otherwise [`Builder::build`] fails
+    /// with a builder error.
     pub fn host<S: Into<String>>(mut self, host: S) -> Builder {
-        self.config.host = Some(host.into());
+        let host = host.into();
+        match Url::parse(&host) {
+            Ok(url) if matches!(url.scheme(), "http" | "https") && url.has_host() => {
+                self.config.host = Some(host);
+            }
+            Ok(_) => {
+                let msg = format!("invalid host {host:?}: expected an http or https url");
+                self.config.error = Some(error::builder(msg));
+            }
+            Err(e) => {
+                self.config.error = Some(error::builder(e));
+            }
+        }
         self
     }
 
@@ -146,6 +184,15 @@ impl Builder {
     /// Set the user agent used for every request.
     ///
     /// Defaults to `"modio/{version}"`
+    ///
+    /// ```no_run
+    /// # fn main() -> modio::Result<()> {
+    /// let modio = modio::Modio::builder("api-key")
+    ///     .user_agent("my-mod-manager/1.0")
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn user_agent<V>(mut self, value: V) -> Builder
     where
         V: TryInto<HeaderValue>,
@@ -163,11 +210,70 @@ impl Builder {
     }
 
     /// Add a `Proxy` to the list of proxies the client will use.
+    ///
+    /// HTTPS requests are tunneled through HTTP proxies with `CONNECT`. Credentials can be
+    /// part of the proxy url or set with [`Proxy::basic_auth`].
+    ///
+    /// Without any configured proxies the client uses the system proxies from the
+    /// `HTTP_PROXY`, `HTTPS_PROXY`, `ALL_PROXY` and `NO_PROXY` environment variables.
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use modio::lib::Proxy;
+    ///
+    /// let proxy = Proxy::all("http://proxy.example.com:8080")?
+    ///     .basic_auth("user", "secret");
+    ///
+    /// let modio = modio::Modio::builder("api-key").proxy(proxy).build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn proxy(mut self, proxy: Proxy) -> Builder {
         self.config.proxies.push(proxy);
         self
     }
 
+    /// Set the default page size for search queries.
+    ///
+    /// The limit is used for every [`Query`] without an explicit limit set by
+    /// [`Filter::limit`] or [`with_limit`]. Defaults to the mod.io page size of `100`.
+    ///
+    /// ```no_run
+    /// # fn main() -> modio::Result<()> {
+    /// let modio = modio::Modio::builder("api-key").default_limit(20).build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Query`]: crate::Query
+    /// [`Filter::limit`]: crate::filter::Filter::limit
+    /// [`with_limit`]: crate::filter::prelude::with_limit
+    pub fn default_limit(mut self, limit: usize) -> Builder {
+        self.config.default_limit = Some(limit);
+        self
+    }
+
+    /// Limit the number of API requests per minute.
+    ///
+    /// Requests are delayed once the quota is used up instead of being rejected by mod.io
+    /// with `429 Too Many Requests`. The limit is shared by all clones of the client and by
+    /// clients created with [`Modio::with_credentials`] or [`Modio::with_token`].
+    ///
+    /// No limit is applied by default.
+    ///
+    /// ```no_run
+    /// # fn main() -> modio::Result<()> {
+    /// let modio = modio::Modio::builder(("api-key", "token"))
+    ///     .max_requests_per_minute(120)
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn max_requests_per_minute(mut self, requests: u32) -> Builder {
+        self.config.max_requests_per_minute = Some(requests);
+        self
+    }
+
     /// Set the target platform.
     ///
     /// See the [mod.io docs](https://docs.mod.io/#targeting-a-platform) for more information.
@@ -211,4 +317,29 @@ impl Builder {
         self.config.tls = TlsBackend::Rustls;
         self
     }
+
+    /// Add a custom root certificate.
+    ///
+    /// The certificate extends the built-in root certificates of the TLS backend. To trust only
+    /// the added certificates, disable the built-in roots with
+    /// [`ClientBuilder::tls_built_in_root_certs`] via [`Builder::client`].
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use modio::lib::Certificate;
+    ///
+    /// let pem = std::fs::read("internal-ca.pem")?;
+    /// let cert = Certificate::from_pem(&pem)?;
+    ///
+    /// let modio = modio::Modio::builder("api-key")
+    ///     .add_root_certificate(cert)
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "__tls")]
+    pub fn add_root_certificate(mut self, cert: Certificate) -> Builder {
+        self.config.root_certs.push(cert);
+        self
+    }
 }
@@ -1,6 +1,9 @@
-use std::sync::Arc;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 
-use reqwest::Client;
+use futures_util::{stream, Stream, StreamExt};
+use reqwest::{Client, Method};
+use url::Url;
 
 use crate::auth::{Auth, Credentials, Token};
 use crate::download::{DownloadAction, Downloader};
@@ -8,14 +11,16 @@ use crate::error::Result;
 use crate::games::{GameRef, Games};
 use crate::mods::ModRef;
 use crate::reports::Reports;
-use crate::request::RequestBuilder;
+use crate::request::{RawRequest, RequestBuilder};
 use crate::routing::Route;
 use crate::types::id::{GameId, ModId};
-use crate::user::Me;
+use crate::user::{Me, User};
 
 mod builder;
+mod ratelimit;
 
 pub use builder::Builder;
+pub(crate) use ratelimit::RateLimiter;
 
 const DEFAULT_HOST: &str = "https://api.mod.io/v1";
 const TEST_HOST: &str = "https://api.test.mod.io/v1";
@@ -32,6 +37,51 @@ pub(crate) struct ClientRef {
     pub(crate) host: String,
     pub(crate) client: Client,
     pub(crate) credentials: Credentials,
+    pub(crate) default_limit: Option<usize>,
+    pub(crate) rate_limiter: Option<Arc<RateLimiter>>,
+    pub(crate) rate_limit: Mutex<Option<RateLimit>>,
+    pub(crate) current_user: Mutex<Option<Arc<User>>>,
+}
+
+impl ClientRef {
+    /// Returns a copy of the client configuration with new credentials.
+    ///
+    /// The rate limiter is shared, the last rate limit and the cached user are reset.
+    fn with_credentials(&self, credentials: Credentials) -> ClientRef {
+        ClientRef {
+            host: self.host.clone(),
+            client: self.client.clone(),
+            credentials,
+            default_limit: self.default_limit,
+            rate_limiter: self.rate_limiter.clone(),
+            rate_limit: Mutex::new(None),
+            current_user: Mutex::new(None),
+        }
+    }
+}
+
+/// Rate limit information of the last API response.
+///
+/// See the [Rate Limiting](https://docs.mod.io/#rate-limiting) docs for more information.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RateLimit {
+    /// Number of requests allowed in the current window (`X-RateLimit-Limit`).
+    pub limit: u32,
+    /// Number of remaining requests in the current window (`X-RateLimit-Remaining`).
+    pub remaining: u32,
+    /// Seconds until requests are allowed again (`Retry-After`).
+    pub retry_after: Option<u64>,
+}
+
+impl RateLimit {
+    pub(crate) fn new(limit: u32, remaining: u32, retry_after: Option<u64>) -> Self {
+        Self {
+            limit,
+            remaining,
+            retry_after,
+        }
+    }
 }
 
 impl Modio {
@@ -66,11 +116,7 @@ impl Modio {
         CR: Into<Credentials>,
     {
         Self {
-            inner: Arc::new(ClientRef {
-                host: self.inner.host.clone(),
-                client: self.inner.client.clone(),
-                credentials: credentials.into(),
-            }),
+            inner: Arc::new(self.inner.with_credentials(credentials.into())),
         }
     }
 
@@ -80,15 +126,23 @@ impl Modio {
     where
         T: Into<Token>,
     {
+        let credentials = Credentials {
+            api_key: self.inner.credentials.api_key.clone(),
+            token: Some(token.into()),
+        };
         Self {
-            inner: Arc::new(ClientRef {
-                host: self.inner.host.clone(),
-                client: self.inner.client.clone(),
-                credentials: Credentials {
-                    api_key: self.inner.credentials.api_key.clone(),
-                    token: Some(token.into()),
-                },
-            }),
+            inner: Arc::new(self.inner.with_credentials(credentials)),
+        }
+    }
+
+    /// Return an endpoint without the access token.
+    ///
+    /// The endpoint uses only the API key for requests like after a [logout](Auth::logout).
+    #[must_use]
+    pub fn without_token(&self) -> Self {
+        let credentials = Credentials::new(self.inner.credentials.api_key.clone());
+        Self {
+            inner: Arc::new(self.inner.with_credentials(credentials)),
         }
     }
 
@@ -182,6 +236,146 @@ impl Modio {
         Downloader::new(self.clone(), action.into()).await
     }
 
+    /// Returns [`Downloader`] if the mod file has changed since the download with the given
+    /// `ETag` or `None` if the mod file is unchanged.
+    ///
+    /// [`Downloader`]: crate::download::Downloader
+    ///
+    /// # Example
+    /// ```no_run
+    /// use modio::types::id::Id;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #    let modio = modio::Modio::new("user-or-game-api-key")?;
+    /// let action = (Id::new(5), Id::new(19));
+    ///
+    /// let downloader = modio.download(action).await?;
+    /// let etag = downloader.etag().map(ToOwned::to_owned);
+    /// downloader.save_to_file("mod.zip").await?;
+    ///
+    /// // Later
+    /// if let Some(etag) = etag {
+    ///     match modio.download_if_changed(action, &etag).await? {
+    ///         Some(downloader) => downloader.save_to_file("mod.zip").await?,
+    ///         None => println!("mod file is unchanged"),
+    ///     }
+    /// }
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn download_if_changed<A>(&self, action: A, etag: &str) -> Result<Option<Downloader>>
+    where
+        DownloadAction: From<A>,
+    {
+        Downloader::new_if_changed(self.clone(), action.into(), etag).await
+    }
+
+    /// Returns [`Downloader`] for an arbitrary url like the logo or the screenshots of a mod.
+    ///
+    /// The request is sent with the configured client, so proxies, root certificates and the
+    /// user agent apply, but without the api key or token.
+    ///
+    /// [`Downloader`]: crate::download::Downloader
+    ///
+    /// # Example
+    /// ```no_run
+    /// use modio::types::id::Id;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #    let modio = modio::Modio::new("user-or-game-api-key")?;
+    /// let mod_ = modio.mod_(Id::new(5), Id::new(19)).get().await?;
+    ///
+    /// modio
+    ///     .download_url(mod_.logo.thumb(320))
+    ///     .await?
+    ///     .save_to_file("logo.png")
+    ///     .await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn download_url(&self, url: &Url) -> Result<Downloader> {
+        Downloader::from_url(self.clone(), url.clone()).await
+    }
+
+    /// Download multiple mod files concurrently and save them to local files.
+    ///
+    /// At most `concurrency` downloads are in flight at the same time, a `concurrency` of `0`
+    /// is treated as `1`. The returned stream yields the path and the result of each download
+    /// in the order of completion.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use modio::types::id::Id;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #    let modio = modio::Modio::new("user-or-game-api-key")?;
+    ///
+    /// let downloads = vec![
+    ///     ((Id::new(5), Id::new(19)), "mod-19.zip"),
+    ///     ((Id::new(5), Id::new(20)), "mod-20.zip"),
+    /// ];
+    ///
+    /// let mut st = modio.download_batch(downloads, 4);
+    /// while let Some((path, result)) = st.next().await {
+    ///     match result {
+    ///         Ok(()) => println!("saved {}", path.display()),
+    ///         Err(e) => println!("failed to download {}: {e}", path.display()),
+    ///     }
+    /// }
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn download_batch<I, A, P>(
+        &self,
+        downloads: I,
+        concurrency: usize,
+    ) -> impl Stream<Item = (PathBuf, Result<()>)>
+    where
+        I: IntoIterator<Item = (A, P)>,
+        DownloadAction: From<A>,
+        P: Into<PathBuf>,
+    {
+        let modio = self.clone();
+        stream::iter(downloads)
+            .map(move |(action, path)| {
+                let modio = modio.clone();
+                let action = DownloadAction::from(action);
+                let path = path.into();
+                async move {
+                    let result = match Downloader::new(modio, action).await {
+                        Ok(downloader) => downloader.save_to_file(&path).await,
+                        Err(e) => Err(e),
+                    };
+                    (path, result)
+                }
+            })
+            .buffer_unordered(concurrency.max(1))
+    }
+
+    /// Returns the rate limit information of the last API response.
+    ///
+    /// The information is shared by all clones of the client, but not by clients created with
+    /// [`Modio::with_credentials`] or [`Modio::with_token`] since rate limits apply per API
+    /// key and access token.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #    let modio = modio::Modio::new(("api-key", "token"))?;
+    /// modio.user().current().await?;
+    ///
+    /// if let Some(rl) = modio.rate_limit() {
+    ///     println!("{}/{} requests remaining", rl.remaining, rl.limit);
+    /// }
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn rate_limit(&self) -> Option<RateLimit> {
+        *self
+            .inner
+            .rate_limit
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+    }
+
     /// Return a reference to an interface that provides access to resources owned by the user
     /// associated with the current authentication credentials.
     pub fn user(&self) -> Me {
@@ -193,6 +387,28 @@ impl Modio {
         Reports::new(self.clone())
     }
 
+    /// Returns a [`RawRequest`] to an API endpoint without a dedicated interface.
+    ///
+    /// The `path` is relative to the host of the client, e.g. `"/games/5/mods"`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use modio::lib::Method;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #    let modio = modio::Modio::new(("api-key", "token"))?;
+    ///
+    /// let value: serde_json::Value = modio
+    ///     .raw_request(Method::GET, "/me/wallets")
+    ///     .query(&[("game_id", "5")])
+    ///     .send()
+    ///     .await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn raw_request(&self, method: Method, path: &str) -> RawRequest {
+        RawRequest::new(self.clone(), method, path)
+    }
+
     pub(crate) fn request(&self, route: Route) -> RequestBuilder {
         RequestBuilder::new(self.clone(), route)
     }
@@ -0,0 +1,83 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tokio::time::{sleep_until, Instant};
+
+/// Paces requests to stay under a number of requests per minute.
+///
+/// Implemented as generic cell rate algorithm which allows bursts up to the full quota
+/// and spaces out the following requests evenly.
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    interval: Duration,
+    tolerance: Duration,
+    tat: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    pub(crate) fn per_minute(requests: u32) -> Self {
+        let requests = requests.max(1);
+        let interval = Duration::from_secs(60) / requests;
+        Self {
+            interval,
+            tolerance: interval * (requests - 1),
+            tat: Mutex::new(None),
+        }
+    }
+
+    /// Waits until the next request is allowed.
+    pub(crate) async fn acquire(&self) {
+        let start = {
+            let mut tat = self.tat.lock().unwrap_or_else(|e| e.into_inner());
+            let now = Instant::now();
+            let current = tat.map_or(now, |t| t.max(now));
+            let start = current
+                .checked_sub(self.tolerance)
+                .map_or(now, |t| t.max(now));
+            *tat = Some(current + self.interval);
+            start
+        };
+        sleep_until(start).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::time::Instant;
+
+    use super::RateLimiter;
+
+    #[tokio::test(start_paused = true)]
+    async fn burst_then_paced() {
+        let limiter = RateLimiter::per_minute(3);
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            limiter.acquire().await;
+        }
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        limiter.acquire().await;
+        assert_eq!(start.elapsed(), Duration::from_secs(20));
+
+        limiter.acquire().await;
+        assert_eq!(start.elapsed(), Duration::from_secs(40));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn quota_recovers() {
+        let limiter = RateLimiter::per_minute(2);
+
+        limiter.acquire().await;
+        limiter.acquire().await;
+
+        tokio::time::advance(Duration::from_secs(60)).await;
+
+        let start = Instant::now();
+        limiter.acquire().await;
+        limiter.acquire().await;
+        assert_eq!(start.elapsed(), Duration::ZERO);
+    }
+}
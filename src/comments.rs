@@ -1,4 +1,5 @@
 //! Mod comments interface
+use std::collections::{HashMap, HashSet};
 
 use serde::ser::{Serialize, SerializeMap, Serializer};
 use serde_derive::Serialize;
@@ -6,6 +7,7 @@ use serde_derive::Serialize;
 use crate::prelude::*;
 use crate::types::id::{CommentId, GameId, ModId};
 pub use crate::types::mods::Comment;
+use crate::types::ErrorRef;
 
 /// Interface for comments of a mod.
 #[derive(Clone)]
@@ -45,7 +47,54 @@ impl Comments {
         self.modio.request(route).send().await
     }
 
+    /// Returns a comment together with all of its replies.
+    ///
+    /// The comments are ordered by their `thread_position`, the root comment comes first and
+    /// every comment is followed by its replies. Use [`build_tree`] to nest the replies.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use modio::types::id::Id;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let modio = modio::Modio::new("api-key")?;
+    /// let comments = modio.mod_(Id::new(5), Id::new(19)).comments();
+    ///
+    /// for comment in comments.thread(Id::new(1)).await? {
+    ///     let indent = "  ".repeat(comment.depth());
+    ///     println!("{indent}{}", comment.content);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn thread(self, root: CommentId) -> Result<Vec<Comment>> {
+        use crate::filter::Like;
+        use filters::ThreadPosition;
+
+        let comment = self.clone().get(root).await?;
+        let filter = ThreadPosition::like(format!("{}.*", comment.thread_position));
+        let mut comments = self.search(filter).collect().await?;
+
+        comments.push(comment);
+        comments.sort_by(|a, b| a.thread_position.cmp(&b.thread_position));
+        Ok(comments)
+    }
+
     /// Add a new comment. [required: token]
+    ///
+    /// Pass the id of an existing comment as `reply_id` to reply to it.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use modio::types::id::Id;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let modio = modio::Modio::new(("api-key", "token"))?;
+    /// let comments = modio.mod_(Id::new(5), Id::new(19)).comments();
+    ///
+    /// let comment = comments.clone().add("Great mod!", None).await?;
+    /// comments.add("Thanks!", Some(comment.id)).await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
     pub async fn add<S>(self, content: S, reply_id: Option<CommentId>) -> Result<Comment>
     where
         S: Into<String>,
@@ -100,7 +149,9 @@ impl Comments {
             .await
             .map(Editing::Entity)
             .or_else(|e| match (e.status(), e.error_ref()) {
-                (Some(StatusCode::FORBIDDEN), Some(15059)) => Ok(Editing::NoChanges),
+                (Some(StatusCode::FORBIDDEN), Some(ErrorRef::COMMENT_KARMA_ALREADY_SUBMITTED)) => {
+                    Ok(Editing::NoChanges)
+                }
                 _ => Err(e),
             })
     }
@@ -156,6 +207,80 @@ pub mod filters {
     filter!(Content, CONTENT, "content", Eq, NotEq, Like);
 }
 
+/// A comment together with its replies. Constructed with [`build_tree`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct CommentNode {
+    pub comment: Comment,
+    pub replies: Vec<CommentNode>,
+}
+
+/// Nest a flat list of comments by their `reply_id`.
+///
+/// Comments are ordered by their `thread_position` on every level. Replies whose parent comment
+/// is not part of the list are returned as top-level nodes.
+///
+/// # Example
+/// ```no_run
+/// # use modio::types::id::Id;
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// #     let modio = modio::Modio::new("api-key")?;
+/// use modio::comments::{build_tree, CommentNode};
+/// use modio::filter::Filter;
+///
+/// fn print(nodes: &[CommentNode]) {
+///     for node in nodes {
+///         let indent = "  ".repeat(node.comment.depth());
+///         println!("{indent}{}", node.comment.content);
+///         print(&node.replies);
+///     }
+/// }
+///
+/// let comments = modio
+///     .mod_(Id::new(5), Id::new(19))
+///     .comments()
+///     .search(Filter::default())
+///     .collect()
+///     .await?;
+///
+/// print(&build_tree(comments));
+/// #     Ok(())
+/// # }
+/// ```
+pub fn build_tree(comments: Vec<Comment>) -> Vec<CommentNode> {
+    fn build(
+        mut comments: Vec<Comment>,
+        replies: &mut HashMap<CommentId, Vec<Comment>>,
+    ) -> Vec<CommentNode> {
+        comments.sort_by(|a, b| a.thread_position.cmp(&b.thread_position));
+        comments
+            .into_iter()
+            .map(|comment| {
+                let replies = replies
+                    .remove(&comment.id)
+                    .map(|c| build(c, replies))
+                    .unwrap_or_default();
+                CommentNode { comment, replies }
+            })
+            .collect()
+    }
+
+    let ids = comments.iter().map(|c| c.id).collect::<HashSet<_>>();
+    let mut roots = Vec::new();
+    let mut replies = HashMap::<_, Vec<_>>::new();
+
+    for comment in comments {
+        match comment.reply_id {
+            Some(parent) if ids.contains(&parent) => {
+                replies.entry(parent).or_default().push(comment)
+            }
+            _ => roots.push(comment),
+        }
+    }
+
+    build(roots, &mut replies)
+}
+
 pub enum Karma {
     Positive,
     Negative,
@@ -178,3 +303,107 @@ struct CommentOptions {
     #[serde(skip_serializing_if = "Option::is_none")]
     reply_id: Option<CommentId>,
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{assert_ser_tokens, Token};
+
+    use super::{build_tree, Comment, CommentOptions};
+    use crate::types::id::Id;
+
+    fn comment(id: u64, reply_id: u64, thread_position: &str) -> Comment {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "resource_id": 1,
+            "user": {
+                "id": 1,
+                "name_id": "xant",
+                "username": "XanT",
+                "date_online": 1_509_386_401,
+                "profile_url": "https://mod.io/u/xant",
+            },
+            "date_added": 1_499_841_487,
+            "reply_id": reply_id,
+            "thread_position": thread_position,
+            "karma": 0,
+            "content": "comment",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn comment_depth() {
+        assert_eq!(comment(1, 0, "01").depth(), 0);
+        assert_eq!(comment(2, 1, "01.01").depth(), 1);
+        assert_eq!(comment(3, 2, "01.01.01").depth(), 2);
+    }
+
+    #[test]
+    fn comment_reply_id() {
+        assert_eq!(comment(1, 0, "01").reply_id, None);
+        assert_eq!(comment(2, 1, "01.01").reply_id.map(|id| id.get()), Some(1));
+    }
+
+    #[test]
+    fn nested_comments() {
+        let comments = vec![
+            comment(4, 2, "01.01.01"),
+            comment(5, 0, "02"),
+            comment(3, 1, "01.02"),
+            comment(2, 1, "01.01"),
+            comment(1, 0, "01"),
+            comment(6, 99, "03.01"),
+        ];
+
+        let tree = build_tree(comments);
+        let ids = tree.iter().map(|n| n.comment.id.get()).collect::<Vec<_>>();
+        assert_eq!(ids, [1, 5, 6]);
+
+        let first = &tree[0];
+        let ids = first.replies.iter().map(|n| n.comment.id.get());
+        assert_eq!(ids.collect::<Vec<_>>(), [2, 3]);
+        assert_eq!(first.replies[0].replies[0].comment.id.get(), 4);
+        assert!(first.replies[1].replies.is_empty());
+        assert!(tree[1].replies.is_empty());
+    }
+
+    #[test]
+    fn serialize_comment_options() {
+        let options = CommentOptions {
+            content: "foo".to_owned(),
+            reply_id: None,
+        };
+        assert_ser_tokens(
+            &options,
+            &[
+                Token::Struct {
+                    name: "CommentOptions",
+                    len: 1,
+                },
+                Token::Str("content"),
+                Token::Str("foo"),
+                Token::StructEnd,
+            ],
+        );
+
+        let options = CommentOptions {
+            content: "foo".to_owned(),
+            reply_id: Some(Id::new(1)),
+        };
+        assert_ser_tokens(
+            &options,
+            &[
+                Token::Struct {
+                    name: "CommentOptions",
+                    len: 2,
+                },
+                Token::Str("content"),
+                Token::Str("foo"),
+                Token::Str("reply_id"),
+                Token::Some,
+                Token::U64(1),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -4,12 +4,16 @@ use std::fmt;
 use std::path::Path;
 
 use bytes::Bytes;
-use futures_util::{SinkExt, Stream, StreamExt, TryFutureExt, TryStreamExt};
+use futures_util::future::BoxFuture;
+use futures_util::{Stream, TryFutureExt, TryStreamExt};
+use reqwest::header::{ETAG, IF_NONE_MATCH};
 use reqwest::{Method, Response, StatusCode};
 use tokio::fs::File as AsyncFile;
-use tokio::io::BufWriter;
-use tokio_util::codec::{BytesCodec, FramedWrite};
+use tokio::io::{AsyncWrite, AsyncWriteExt, BufWriter};
+use tokio::sync::{mpsc, watch};
+use tokio::task::JoinHandle;
 use tracing::debug;
+use url::Url;
 
 use crate::error::{self, Result};
 use crate::types::files::File;
@@ -17,17 +21,47 @@ use crate::types::id::{FileId, GameId, ModId};
 use crate::types::mods::Mod;
 use crate::Modio;
 
+const DEFAULT_BUFFER_SIZE: usize = 512 * 512;
+const PROGRESS_CHANNEL_CAPACITY: usize = 16;
+
 /// A `Downloader` can be used to stream a mod file or save the file to a local file.
 /// Constructed with [`Modio::download`].
 pub struct Downloader(Response);
 
 impl Downloader {
     pub(crate) async fn new(modio: Modio, action: DownloadAction) -> Result<Self> {
-        Ok(Self(request_file(modio, action).await?))
+        Ok(Self(request_file(modio, action, None).await?))
+    }
+
+    pub(crate) async fn from_url(modio: Modio, url: Url) -> Result<Self> {
+        Ok(Self(request_url(&modio, url, None).await?))
+    }
+
+    pub(crate) async fn new_if_changed(
+        modio: Modio,
+        action: DownloadAction,
+        etag: &str,
+    ) -> Result<Option<Self>> {
+        let response = request_file(modio, action, Some(etag)).await?;
+        if response.status() == StatusCode::NOT_MODIFIED {
+            return Ok(None);
+        }
+        Ok(Some(Self(response)))
+    }
+
+    /// Get the `ETag` from the mod file response.
+    ///
+    /// The value can be passed to [`Modio::download_if_changed`] to skip the download of an
+    /// unchanged mod file.
+    pub fn etag(&self) -> Option<&str> {
+        self.0.headers().get(ETAG).and_then(|v| v.to_str().ok())
     }
 
     /// Save the mod file to a local file.
     ///
+    /// The file is written with a buffer of 256 KiB, see [`Downloader::save_to_file_buffered`]
+    /// for a different buffer size.
+    ///
     /// # Example
     /// ```no_run
     /// # use modio::types::id::Id;
@@ -47,11 +81,178 @@ impl Downloader {
     /// # }
     /// ```
     pub async fn save_to_file<P: AsRef<Path>>(self, file: P) -> Result<()> {
-        let out = AsyncFile::create(file).map_err(error::decode).await?;
-        let out = BufWriter::with_capacity(512 * 512, out);
-        let out = FramedWrite::new(out, BytesCodec::new());
-        let out = SinkExt::<Bytes>::sink_map_err(out, error::decode);
-        self.stream().forward(out).await
+        self.save_to_file_buffered(file, DEFAULT_BUFFER_SIZE).await
+    }
+
+    /// Save the mod file to a local file with a write buffer of `capacity` bytes.
+    ///
+    /// A larger buffer reduces the number of writes on slow disks or network mounts, a smaller
+    /// one the memory usage on constrained devices.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use modio::types::id::Id;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let modio = modio::Modio::new("api-key")?;
+    /// let action = modio::DownloadAction::Primary {
+    ///     game_id: Id::new(5),
+    ///     mod_id: Id::new(19),
+    /// };
+    ///
+    /// modio
+    ///     .download(action)
+    ///     .await?
+    ///     .save_to_file_buffered("mod.zip", 4 * 1024 * 1024)
+    ///     .await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn save_to_file_buffered<P: AsRef<Path>>(
+        self,
+        file: P,
+        capacity: usize,
+    ) -> Result<()> {
+        let out = create_file(file.as_ref(), capacity).await?;
+        self.save_to_writer(out).await
+    }
+
+    /// Save the mod file to a local file and report the progress through a [`watch`] channel.
+    ///
+    /// The returned future performs the download and can be awaited or spawned as a task.
+    /// The receiver always holds the latest [`Progress`], slow receivers don't hold up the
+    /// download.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use modio::types::id::Id;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let modio = modio::Modio::new("api-key")?;
+    /// let action = modio::DownloadAction::Primary {
+    ///     game_id: Id::new(5),
+    ///     mod_id: Id::new(19),
+    /// };
+    ///
+    /// let downloader = modio.download(action).await?;
+    /// let (download, mut progress) = downloader.save_to_file_with_progress("mod.zip");
+    /// let task = tokio::spawn(download);
+    ///
+    /// while progress.changed().await.is_ok() {
+    ///     let p = *progress.borrow_and_update();
+    ///     println!("{}/{:?} bytes", p.downloaded, p.total);
+    /// }
+    /// task.await??;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`watch`]: tokio::sync::watch
+    pub fn save_to_file_with_progress<P: AsRef<Path>>(
+        self,
+        file: P,
+    ) -> (BoxFuture<'static, Result<()>>, watch::Receiver<Progress>) {
+        let path = file.as_ref().to_path_buf();
+        let total = self.content_length();
+        let (tx, rx) = watch::channel(Progress {
+            downloaded: 0,
+            total,
+        });
+
+        let download = async move {
+            let out = create_file(&path, DEFAULT_BUFFER_SIZE).await?;
+            self.write_to(out, |downloaded| {
+                tx.send_replace(Progress { downloaded, total });
+            })
+            .await
+        };
+        (Box::pin(download), rx)
+    }
+
+    /// Save the mod file to a local file in a spawned task and send the progress through an
+    /// [`mpsc`] channel.
+    ///
+    /// The returned [`JoinHandle`] resolves once the download is complete. A progress update is
+    /// skipped if the channel is full, so slow receivers don't hold up the download. Must be
+    /// called from within a Tokio runtime.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use modio::types::id::Id;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let modio = modio::Modio::new("api-key")?;
+    /// let action = modio::DownloadAction::Primary {
+    ///     game_id: Id::new(5),
+    ///     mod_id: Id::new(19),
+    /// };
+    ///
+    /// let downloader = modio.download(action).await?;
+    /// let (task, mut progress) = downloader.save_to_file_with_channel("mod.zip");
+    ///
+    /// while let Some(p) = progress.recv().await {
+    ///     println!("{}/{:?} bytes", p.downloaded, p.total);
+    /// }
+    /// task.await??;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`mpsc`]: tokio::sync::mpsc
+    /// [`JoinHandle`]: tokio::task::JoinHandle
+    pub fn save_to_file_with_channel<P: AsRef<Path>>(
+        self,
+        file: P,
+    ) -> (JoinHandle<Result<()>>, mpsc::Receiver<Progress>) {
+        let path = file.as_ref().to_path_buf();
+        let total = self.content_length();
+        let (tx, rx) = mpsc::channel(PROGRESS_CHANNEL_CAPACITY);
+
+        let task = tokio::spawn(async move {
+            let out = create_file(&path, DEFAULT_BUFFER_SIZE).await?;
+            self.write_to(out, |downloaded| {
+                let _ = tx.try_send(Progress { downloaded, total });
+            })
+            .await
+        });
+        (task, rx)
+    }
+
+    /// Write the mod file to an [`AsyncWrite`] writer.
+    ///
+    /// The writer is flushed after the last chunk has been written but it is not shut down.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use modio::types::id::Id;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let modio = modio::Modio::new("api-key")?;
+    /// let action = modio::DownloadAction::Primary {
+    ///     game_id: Id::new(5),
+    ///     mod_id: Id::new(19),
+    /// };
+    ///
+    /// let mut buf = Vec::new();
+    /// modio.download(action).await?.save_to_writer(&mut buf).await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn save_to_writer<W: AsyncWrite + Unpin>(self, writer: W) -> Result<()> {
+        self.write_to(writer, |_| {}).await
+    }
+
+    /// Writes the mod file to `writer` and calls `on_chunk` with the number of bytes written
+    /// so far after each chunk.
+    async fn write_to<W, F>(self, mut writer: W, mut on_chunk: F) -> Result<()>
+    where
+        W: AsyncWrite + Unpin,
+        F: FnMut(u64),
+    {
+        let mut stream = Box::pin(self.stream());
+        let mut downloaded = 0;
+        while let Some(bytes) = stream.try_next().await? {
+            writer.write_all(&bytes).map_err(error::decode).await?;
+            downloaded += bytes.len() as u64;
+            on_chunk(downloaded);
+        }
+        writer.flush().map_err(error::decode).await
     }
 
     /// Get the full mod file as `Bytes`.
@@ -124,26 +325,32 @@ impl Downloader {
     }
 }
 
-async fn request_file(modio: Modio, action: DownloadAction) -> Result<Response> {
+/// Progress of a download started with [`Downloader::save_to_file_with_progress`] or
+/// [`Downloader::save_to_file_with_channel`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Progress {
+    /// Number of bytes downloaded so far.
+    pub downloaded: u64,
+    /// Total number of bytes if the response has a content length.
+    pub total: Option<u64>,
+}
+
+async fn create_file(path: &Path, capacity: usize) -> Result<BufWriter<AsyncFile>> {
+    let out = AsyncFile::create(path).map_err(error::decode).await?;
+    Ok(BufWriter::with_capacity(capacity, out))
+}
+
+async fn request_file(
+    modio: Modio,
+    action: DownloadAction,
+    etag: Option<&str>,
+) -> Result<Response> {
     let url = match action {
         DownloadAction::Primary { game_id, mod_id } => {
-            let modref = modio.mod_(game_id, mod_id);
-            let m = modref
-                .get()
-                .map_err(|e| match e.status() {
-                    Some(StatusCode::NOT_FOUND) => {
-                        let source = Error::ModNotFound { game_id, mod_id };
-                        error::download(source)
-                    }
-                    _ => e,
-                })
-                .await?;
-            if let Some(file) = m.modfile {
-                file.download.binary_url
-            } else {
-                let source = Error::NoPrimaryFile { game_id, mod_id };
-                return Err(error::download(source));
-            }
+            let files = modio.mod_(game_id, mod_id).files();
+            let file = files.get_primary().await?;
+            file.download.binary_url
         }
         DownloadAction::FileObj(file) => file.download.binary_url,
         DownloadAction::File {
@@ -174,62 +381,21 @@ async fn request_file(modio: Modio, action: DownloadAction) -> Result<Response>
             version,
             policy,
         } => {
-            use crate::files::filters::Version;
-            use crate::filter::prelude::*;
-            use ResolvePolicy::*;
-
-            let filter = Version::eq(version.clone())
-                .order_by(DateAdded::desc())
-                .limit(2);
-
             let files = modio.mod_(game_id, mod_id).files();
-            let mut list = files
-                .search(filter)
-                .first_page()
-                .map_err(|e| match e.status() {
-                    Some(StatusCode::NOT_FOUND) => {
-                        let source = Error::ModNotFound { game_id, mod_id };
-                        error::download(source)
-                    }
-                    _ => e,
-                })
-                .await?;
-
-            let (file, error) = match (list.len(), policy) {
-                (0, _) => (
-                    None,
-                    Some(Error::VersionNotFound {
-                        game_id,
-                        mod_id,
-                        version,
-                    }),
-                ),
-                (1, _) | (_, Latest) => (Some(list.remove(0)), None),
-                (_, Fail) => (
-                    None,
-                    Some(Error::MultipleFilesFound {
-                        game_id,
-                        mod_id,
-                        version,
-                    }),
-                ),
-            };
-
-            if let Some(file) = file {
-                file.download.binary_url
-            } else {
-                let source = error.expect("bug in previous match!");
-                return Err(error::download(source));
-            }
+            let file = files.get_by_version(version, policy).await?;
+            file.download.binary_url
         }
     };
+    request_url(&modio, url, etag).await
+}
 
+async fn request_url(modio: &Modio, url: Url, etag: Option<&str>) -> Result<Response> {
     debug!("downloading file: {}", url);
-    modio
-        .inner
-        .client
-        .request(Method::GET, url)
-        .send()
+    let mut req = modio.inner.client.request(Method::GET, url);
+    if let Some(etag) = etag {
+        req = req.header(IF_NONE_MATCH, etag);
+    }
+    req.send()
         .map_err(error::builder_or_request)
         .await?
         .error_for_status()
@@ -248,6 +414,20 @@ pub enum DownloadAction {
         file_id: FileId,
     },
     /// Download a specific modfile.
+    ///
+    /// The download url of the file is used directly without requesting the file again.
+    /// Created with `DownloadAction::from(file)`.
+    ///
+    /// ```no_run
+    /// use modio::types::id::Id;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #    let modio = modio::Modio::new("user-or-game-api-key")?;
+    /// let file = modio.mod_(Id::new(5), Id::new(19)).file(Id::new(101)).get().await?;
+    ///
+    /// modio.download(file).await?.save_to_file("mod.zip").await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
     FileObj(Box<File>),
     /// Download a specific version of a mod.
     Version {
@@ -258,17 +438,54 @@ pub enum DownloadAction {
     },
 }
 
-/// Defines the policy for `DownloadAction::Version` when multiple files are found.
+/// Defines the policy for `DownloadAction::Version` and [`Files::get_by_version`] when multiple
+/// files are found.
+///
+/// [`Files::get_by_version`]: crate::files::Files::get_by_version
 #[derive(Debug)]
 pub enum ResolvePolicy {
     /// Download the latest file.
     Latest,
+    /// Download the latest active file.
+    ///
+    /// A file is active if the virus scan completed without finding threats, as with
+    /// [`Files::active_only`], and it is approved for at least one of its target platforms or
+    /// has no target platforms. All files of the version are searched, returns with
+    /// [`Error::NoActiveFileFound`] as source error if none of the files is active.
+    ///
+    /// [`Files::active_only`]: crate::files::Files::active_only
+    LatestActive,
     /// Return with [`Error::MultipleFilesFound`] as source error.
     Fail,
 }
 
 /// The Errors that may occur when using [`Modio::download`].
+///
+/// The error is returned as source of a [`modio::Error`](crate::Error), use
+/// [`Error::download_error`](crate::Error::download_error) to match on it. Request and I/O
+/// errors are reported with [`Error::is_request`](crate::Error::is_request) and
+/// [`Error::is_decode`](crate::Error::is_decode).
+///
+/// # Example
+/// ```no_run
+/// use modio::download::Error as DownloadError;
+/// use modio::types::id::Id;
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// #     let modio = modio::Modio::new("api-key")?;
+///
+/// match modio.download((Id::new(5), Id::new(19), "1.1")).await {
+///     Ok(downloader) => downloader.save_to_file("mod.zip").await?,
+///     Err(e) => match e.download_error() {
+///         Some(DownloadError::VersionNotFound { .. }) => println!("unknown version"),
+///         Some(DownloadError::MultipleFilesFound { .. }) => println!("ambiguous version"),
+///         _ => return Err(e.into()),
+///     },
+/// }
+/// #     Ok(())
+/// # }
+/// ```
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Error {
     /// The mod has not found.
     ModNotFound { game_id: GameId, mod_id: ModId },
@@ -293,6 +510,13 @@ pub enum Error {
         mod_id: ModId,
         version: String,
     },
+    /// Files for a given version were found but none of them is active and the policy was set
+    /// to [`ResolvePolicy::LatestActive`].
+    NoActiveFileFound {
+        game_id: GameId,
+        mod_id: ModId,
+        version: String,
+    },
 }
 
 impl StdError for Error {}
@@ -332,6 +556,14 @@ impl fmt::Display for Error {
                 fmt,
                 "Mod {{id: {mod_id}, game_id: {game_id}}}: No file with version '{version}' found.",
             ),
+            Error::NoActiveFileFound {
+                game_id,
+                mod_id,
+                version,
+            } => write!(
+                fmt,
+                "Mod {{id: {mod_id}, game_id: {game_id}}}: No active file with version '{version}' found.",
+            ),
         }
     }
 }
@@ -5,7 +5,9 @@ use std::time::Duration;
 
 use reqwest::StatusCode;
 
+use crate::download::Error as DownloadError;
 use crate::types::Error as ApiError;
+use crate::types::ErrorRef;
 
 /// A `Result` alias where the `Err` case is `modio::Error`.
 pub type Result<T, E = Error> = std::result::Result<T, E>;
@@ -84,6 +86,53 @@ impl Error {
         matches!(self.inner.kind, Kind::Validation { .. })
     }
 
+    /// Returns true if the requested resource was not found (`404 Not Found`).
+    pub fn is_not_found(&self) -> bool {
+        self.status() == Some(StatusCode::NOT_FOUND)
+    }
+
+    /// Returns true if the API key/access token is incorrect, revoked or expired
+    /// (`401 Unauthorized`).
+    ///
+    /// A missing access token is reported before sending the request, use
+    /// [`Error::is_auth`] to check for both.
+    pub fn is_unauthorized(&self) -> bool {
+        matches!(self.inner.kind, Kind::Unauthorized)
+    }
+
+    /// Returns true if the authenticated user is not allowed to perform the request
+    /// (`403 Forbidden`).
+    pub fn is_forbidden(&self) -> bool {
+        matches!(self.inner.kind, Kind::TermsAcceptanceRequired)
+            || self.status() == Some(StatusCode::FORBIDDEN)
+    }
+
+    /// Returns true if the security code of an email exchange is invalid, expired or has
+    /// already been redeemed.
+    ///
+    /// A new code must be requested with [`Auth::request_code`](crate::auth::Auth::request_code).
+    pub fn is_invalid_code(&self) -> bool {
+        matches!(
+            self.error_ref(),
+            Some(
+                ErrorRef::SECURITY_CODE_ALREADY_REDEEMED
+                    | ErrorRef::SECURITY_CODE_EXPIRED
+                    | ErrorRef::SECURITY_CODE_OTHER_API_KEY
+                    | ErrorRef::SECURITY_CODE_INVALID
+            )
+        )
+    }
+
+    /// Returns true if the error was generated from a `5xx` server error response.
+    pub fn is_server_error(&self) -> bool {
+        self.status().is_some_and(|s| s.is_server_error())
+    }
+
+    /// Returns true if the error is from sending the request or receiving the response.
+    pub fn is_request(&self) -> bool {
+        matches!(self.inner.kind, Kind::Request)
+    }
+
     /// Returns true if the error is related to serialization.
     pub fn is_decode(&self) -> bool {
         matches!(self.inner.kind, Kind::Decode)
@@ -97,11 +146,20 @@ impl Error {
         }
     }
 
+    /// Returns the download error if the error is from a
+    /// [`DownloadAction`](crate::download::DownloadAction).
+    pub fn download_error(&self) -> Option<&DownloadError> {
+        match self.inner.kind {
+            Kind::Download => self.inner.source.as_ref()?.downcast_ref(),
+            _ => None,
+        }
+    }
+
     /// Returns modio's error reference code.
     ///
     /// See the [Error Codes](https://docs.mod.io/#error-codes) docs for more information.
-    pub fn error_ref(&self) -> Option<u16> {
-        self.inner.error_ref
+    pub fn error_ref(&self) -> Option<ErrorRef> {
+        self.inner.error_ref.map(ErrorRef::new)
     }
 
     /// Returns status code if the error was generated from a response.
@@ -122,6 +180,16 @@ impl Error {
             _ => None,
         }
     }
+
+    /// Returns the field-level validation errors as `(field, message)` pairs.
+    ///
+    /// Only populated for `422 Unprocessable Entity` responses.
+    pub fn validation_errors(&self) -> Option<&[(String, String)]> {
+        match self.inner.kind {
+            Kind::Validation { ref errors, .. } => Some(errors),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Debug for Error {
@@ -239,7 +307,11 @@ pub(crate) fn error_for_status(status: StatusCode, error: ApiError) -> Error {
             errors: error.errors,
         },
         StatusCode::UNAUTHORIZED => Kind::Unauthorized,
-        StatusCode::FORBIDDEN if error_ref == 11051 => Kind::TermsAcceptanceRequired,
+        StatusCode::FORBIDDEN
+            if ErrorRef::new(error_ref) == ErrorRef::TERMS_ACCEPTANCE_REQUIRED =>
+        {
+            Kind::TermsAcceptanceRequired
+        }
         _ => Kind::Response { status, error },
     };
     Error::new(kind).with_error_ref(error_ref)
@@ -254,3 +326,77 @@ pub(crate) fn ratelimit(retry_after: u64) -> Error {
 pub(crate) fn download<E: Into<BoxError>>(source: E) -> Error {
     Error::new(Kind::Download).with(source)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn api_error(code: u16, error_ref: u16, errors: Vec<(String, String)>) -> ApiError {
+        ApiError {
+            code,
+            error_ref,
+            message: String::from("error message"),
+            errors,
+        }
+    }
+
+    #[test]
+    fn validation_errors() {
+        let errors = vec![("name".to_owned(), "The name is required.".to_owned())];
+        let error = api_error(422, 13009, errors.clone());
+        let err = error_for_status(StatusCode::UNPROCESSABLE_ENTITY, error);
+
+        assert!(err.is_validation());
+        assert_eq!(err.validation_errors(), Some(&errors[..]));
+
+        let err = error_for_status(StatusCode::NOT_FOUND, api_error(404, 14000, vec![]));
+        assert_eq!(err.validation_errors(), None);
+    }
+
+    #[test]
+    fn status_predicates() {
+        let err = error_for_status(StatusCode::NOT_FOUND, api_error(404, 14000, vec![]));
+        assert!(err.is_not_found());
+        assert!(!err.is_forbidden());
+
+        let err = error_for_status(StatusCode::UNAUTHORIZED, api_error(401, 11005, vec![]));
+        assert!(err.is_unauthorized());
+        assert!(err.is_auth());
+        assert!(!err.is_not_found());
+
+        let err = error_for_status(StatusCode::FORBIDDEN, api_error(403, 11051, vec![]));
+        assert!(err.is_forbidden());
+        assert!(err.is_terms_acceptance_required());
+        assert_eq!(err.error_ref(), Some(ErrorRef::TERMS_ACCEPTANCE_REQUIRED));
+
+        let err = error_for_status(StatusCode::FORBIDDEN, api_error(403, 15023, vec![]));
+        assert!(err.is_forbidden());
+
+        let err = error_for_status(StatusCode::BAD_GATEWAY, api_error(502, 10000, vec![]));
+        assert!(err.is_server_error());
+        assert!(!err.is_not_found());
+    }
+
+    #[test]
+    fn invalid_security_code() {
+        let payload = r#"{
+            "error": {
+                "code": 400,
+                "error_ref": 11012,
+                "message": "The security code has expired. Please request a new code."
+            }
+        }"#;
+        let resp: crate::types::ErrorResponse = serde_json::from_str(payload).unwrap();
+        let err = error_for_status(StatusCode::BAD_REQUEST, resp.error);
+        assert!(err.is_invalid_code());
+        assert_eq!(err.error_ref(), Some(ErrorRef::SECURITY_CODE_EXPIRED));
+
+        for error_ref in [11011, 11013, 11014] {
+            let err = error_for_status(StatusCode::BAD_REQUEST, api_error(400, error_ref, vec![]));
+            assert!(err.is_invalid_code());
+        }
+
+        let err = error_for_status(StatusCode::UNAUTHORIZED, api_error(401, 11005, vec![]));
+        assert!(!err.is_invalid_code());
+    }
+}
@@ -1,8 +1,9 @@
 use std::path::Path;
 
+use bytes::Bytes;
 use futures_util::TryFutureExt;
 use mime::Mime;
-use reqwest::multipart::Part;
+use reqwest::multipart::{Form, Part};
 use reqwest::Body;
 use tokio::fs::File;
 use tokio::io::AsyncRead;
@@ -12,11 +13,14 @@ pub struct FileSource {
     pub body: Body,
     pub filename: String,
     pub mime: Mime,
+    /// Known length of a streamed body, used for the `Content-Length` of the multipart request.
+    pub length: Option<u64>,
 }
 
 impl FileSource {
     pub fn new_from_file<P: AsRef<Path>>(file: P, filename: String, mime: Mime) -> Self {
         let file = file.as_ref().to_path_buf();
+        let length = std::fs::metadata(&file).ok().map(|m| m.len());
         let st = File::open(file)
             .map_ok(ReaderStream::new)
             .try_flatten_stream();
@@ -25,6 +29,16 @@ impl FileSource {
             body: Body::wrap_stream(st),
             filename,
             mime,
+            length,
+        }
+    }
+
+    pub fn new_from_bytes(bytes: Bytes, filename: String, mime: Mime) -> Self {
+        FileSource {
+            body: Body::from(bytes),
+            filename,
+            mime,
+            length: None,
         }
     }
 
@@ -36,15 +50,49 @@ impl FileSource {
             body: Body::wrap_stream(ReaderStream::new(read)),
             filename,
             mime,
+            length: None,
         }
     }
 }
 
 impl From<FileSource> for Part {
     fn from(source: FileSource) -> Part {
-        Part::stream(source.body)
-            .file_name(source.filename)
+        let part = match source.length {
+            Some(length) => Part::stream_with_length(source.body, length),
+            None => Part::stream(source.body),
+        };
+        part.file_name(source.filename)
             .mime_str(source.mime.as_ref())
             .expect("FileSource::into::<Part>()")
     }
 }
+
+/// Percent-encoding of the field names of multipart uploads.
+///
+/// Field names with encoded characters are sent in the RFC 5987 form `name*=utf-8''...`,
+/// otherwise as plain `name="..."`. Filenames are never encoded.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PercentEncoding {
+    /// Percent-encode with the path segment rules which keep field names like `platforms[]`.
+    ///
+    /// This is the default.
+    #[default]
+    PathSegment,
+    /// Percent-encode with the stricter `attr-char` rules, e.g. `name*=utf-8''platforms%5B%5D`.
+    AttrChars,
+    /// Never encode and always send plain `name="..."` field names.
+    ///
+    /// Use this for servers or proxies that mishandle the `name*=` form.
+    NoOp,
+}
+
+impl PercentEncoding {
+    pub(crate) fn apply(self, form: Form) -> Form {
+        match self {
+            Self::PathSegment => form.percent_encode_path_segment(),
+            Self::AttrChars => form.percent_encode_attr_chars(),
+            Self::NoOp => form.percent_encode_noop(),
+        }
+    }
+}
@@ -1,15 +1,20 @@
 //! Modfile interface
 use std::ffi::OsStr;
-use std::path::Path;
+use std::io;
+use std::path::{Path, PathBuf};
 
+use futures_util::TryStreamExt;
 use mime::APPLICATION_OCTET_STREAM;
 use serde::ser::{Serialize, SerializeMap, Serializer};
 use tokio::io::AsyncRead;
+use tokio_util::io::ReaderStream;
 
+use crate::download::{self, ResolvePolicy};
+use crate::error;
 use crate::file_source::FileSource;
 use crate::prelude::*;
 use crate::types::id::{FileId, GameId, ModId};
-use crate::TargetPlatform;
+use crate::{PercentEncoding, TargetPlatform};
 
 pub use crate::types::files::{
     Download, File, FileHash, Platform, PlatformStatus, VirusResult, VirusScan, VirusStatus,
@@ -44,14 +49,257 @@ impl Files {
         Query::new(self.modio.clone(), route, filter)
     }
 
+    /// Returns a `Query` interface to retrieve the files that passed the virus scan without
+    /// detected threats.
+    ///
+    /// The platform status of the files can't be filtered, check the [`File::platforms`] of
+    /// the returned files for the approved platforms.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures_util::TryStreamExt;
+    /// use modio::files::PlatformStatus;
+    /// use modio::types::id::Id;
+    /// use modio::TargetPlatform;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let modio = modio::Modio::new("api-key")?;
+    ///
+    /// let files = modio.mod_(Id::new(5), Id::new(19)).files();
+    /// let mut st = files.active_only().iter().await?;
+    /// while let Some(file) = st.try_next().await? {
+    ///     let windows = file.platforms.iter().any(|p| {
+    ///         p.target == TargetPlatform::WINDOWS && p.status == PlatformStatus::APPROVED
+    ///     });
+    ///     println!("{}: windows={windows}", file.filename);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn active_only(&self) -> Query<File> {
+        use crate::filter::Eq;
+
+        let (status, result) = ACTIVE_VIRUS_SCAN;
+        let filter = filters::VirusStatus::eq(status).and(filters::VirusPositive::eq(result));
+        self.search(filter)
+    }
+
+    /// Return the `count` most recently added files, newest first.
+    ///
+    /// Counts above the maximum page size of `100` are fetched in multiple pages.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use modio::types::id::Id;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let modio = modio::Modio::new("api-key")?;
+    ///
+    /// let files = modio.mod_(Id::new(5), Id::new(19)).files();
+    /// for file in files.latest(3).await? {
+    ///     println!("{} {:?}", file.filename, file.version);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn latest(self, count: usize) -> Result<Vec<File>> {
+        use crate::filter::OrderBy;
+        use filters::DateAdded;
+        use futures_util::StreamExt;
+
+        if count == 0 {
+            return Ok(Vec::new());
+        }
+        // mod.io returns at most 100 items per page.
+        let filter = DateAdded::desc().limit(count.min(100));
+        self.search(filter)
+            .iter()
+            .await?
+            .take(count)
+            .try_collect()
+            .await
+    }
+
+    /// Return the most recently added file or `None` if the mod has no files.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use modio::types::id::Id;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let modio = modio::Modio::new("api-key")?;
+    ///
+    /// let files = modio.mod_(Id::new(5), Id::new(19)).files();
+    /// if let Some(file) = files.newest().await? {
+    ///     println!("{} {:?}", file.filename, file.version);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn newest(self) -> Result<Option<File>> {
+        use crate::filter::OrderBy;
+        use filters::DateAdded;
+
+        self.search(DateAdded::desc()).first().await
+    }
+
     /// Return a reference to a file.
     pub fn get(&self, id: FileId) -> FileRef {
         FileRef::new(self.modio.clone(), self.game, self.mod_id, id)
     }
 
+    /// Return the file of a mod with the given version.
+    ///
+    /// If multiple files with the same version are found, the `policy` decides whether the
+    /// latest (active) file is returned or [`download::Error::MultipleFilesFound`] is returned as
+    /// source error.
+    ///
+    /// Errors for a missing mod or version are returned with a [`download::Error`] as source.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use modio::download::ResolvePolicy;
+    /// use modio::types::id::Id;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let modio = modio::Modio::new("api-key")?;
+    ///
+    /// let files = modio.mod_(Id::new(5), Id::new(19)).files();
+    /// let file = files.get_by_version("1.1", ResolvePolicy::Latest).await?;
+    /// println!("{} {:?}", file.filename, file.filehash.md5);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn get_by_version<S: Into<String>>(
+        self,
+        version: S,
+        policy: ResolvePolicy,
+    ) -> Result<File> {
+        use crate::filter::prelude::*;
+        use filters::Version;
+        use ResolvePolicy::*;
+
+        let (game_id, mod_id) = (self.game, self.mod_id);
+        let version = version.into();
+        let filter = Version::eq(version.clone()).order_by(DateAdded::desc());
+        let mod_not_found = |e: error::Error| match e.status() {
+            Some(StatusCode::NOT_FOUND) => {
+                let source = download::Error::ModNotFound { game_id, mod_id };
+                error::download(source)
+            }
+            _ => e,
+        };
+
+        if let LatestActive = policy {
+            // An older active file can be on any of the following pages.
+            let mut st = self.search(filter).iter().await.map_err(mod_not_found)?;
+            let mut found = false;
+            while let Some(file) = st.try_next().await? {
+                if is_active(&file) {
+                    return Ok(file);
+                }
+                found = true;
+            }
+            let source = if found {
+                download::Error::NoActiveFileFound {
+                    game_id,
+                    mod_id,
+                    version,
+                }
+            } else {
+                download::Error::VersionNotFound {
+                    game_id,
+                    mod_id,
+                    version,
+                }
+            };
+            return Err(error::download(source));
+        }
+
+        let mut list = self
+            .search(filter.limit(2))
+            .first_page()
+            .await
+            .map_err(mod_not_found)?;
+
+        match (list.len(), policy) {
+            (0, _) => Err(error::download(download::Error::VersionNotFound {
+                game_id,
+                mod_id,
+                version,
+            })),
+            (1, _) | (_, Latest | LatestActive) => Ok(list.remove(0)),
+            (_, Fail) => Err(error::download(download::Error::MultipleFilesFound {
+                game_id,
+                mod_id,
+                version,
+            })),
+        }
+    }
+
+    /// Return the primary file of a mod, the `modfile` of the [`Mod`] object.
+    ///
+    /// Errors for a missing mod or primary file are returned with a [`download::Error`] as
+    /// source.
+    ///
+    /// [`Mod`]: crate::mods::Mod
+    ///
+    /// # Example
+    /// ```no_run
+    /// use modio::types::id::Id;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let modio = modio::Modio::new("api-key")?;
+    ///
+    /// let file = modio.mod_(Id::new(5), Id::new(19)).files().get_primary().await?;
+    /// println!("{} {} bytes", file.filename, file.filesize);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn get_primary(self) -> Result<File> {
+        let (game_id, mod_id) = (self.game, self.mod_id);
+        let m = self
+            .modio
+            .mod_(game_id, mod_id)
+            .get()
+            .await
+            .map_err(|e| match e.status() {
+                Some(StatusCode::NOT_FOUND) => {
+                    let source = download::Error::ModNotFound { game_id, mod_id };
+                    error::download(source)
+                }
+                _ => e,
+            })?;
+
+        m.modfile.ok_or_else(|| {
+            let source = download::Error::NoPrimaryFile { game_id, mod_id };
+            error::download(source)
+        })
+    }
+
     /// Add a file for a mod that this `Files` refers to. [required: token]
+    ///
+    /// The file is uploaded in a single request. If the options were created from a file path
+    /// and no `filehash` is set, the MD5 hash of the file is calculated and submitted for
+    /// validation. See [`AddFileOptions::skip_hash`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// use modio::files::AddFileOptions;
+    /// use modio::types::id::Id;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let modio = modio::Modio::new("api-key")?;
+    ///
+    /// let options = AddFileOptions::with_file("mod.zip")
+    ///     .version("1.1")
+    ///     .changelog("Fixed the crash on startup")
+    ///     .active(true);
+    ///
+    /// let file = modio.mod_(Id::new(5), Id::new(19)).files().add(options).await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
     #[allow(clippy::should_implement_trait)]
-    pub async fn add(self, options: AddFileOptions) -> Result<File> {
+    pub async fn add(self, mut options: AddFileOptions) -> Result<File> {
+        if let (None, Some(path), false) = (&options.filehash, &options.path, options.skip_hash) {
+            let filehash = md5_file(path).await.map_err(error::decode)?;
+            options.filehash = Some(filehash);
+        }
         let route = Route::AddFile {
             game_id: self.game,
             mod_id: self.mod_id,
@@ -181,11 +429,15 @@ pub mod filters {
 
 pub struct AddFileOptions {
     source: FileSource,
+    path: Option<PathBuf>,
+    skip_hash: bool,
     version: Option<String>,
     changelog: Option<String>,
     active: Option<bool>,
     filehash: Option<String>,
     metadata_blob: Option<String>,
+    platforms: Vec<TargetPlatform>,
+    encoding: PercentEncoding,
 }
 
 impl AddFileOptions {
@@ -196,11 +448,15 @@ impl AddFileOptions {
     {
         AddFileOptions {
             source: FileSource::new_from_read(inner, filename.into(), APPLICATION_OCTET_STREAM),
+            path: None,
+            skip_hash: false,
             version: None,
             changelog: None,
             active: None,
             filehash: None,
             metadata_blob: None,
+            platforms: Vec::new(),
+            encoding: PercentEncoding::default(),
         }
     }
 
@@ -223,11 +479,15 @@ impl AddFileOptions {
 
         AddFileOptions {
             source: FileSource::new_from_file(file, filename.into(), APPLICATION_OCTET_STREAM),
+            path: Some(file.to_path_buf()),
+            skip_hash: false,
             version: None,
             changelog: None,
             active: None,
             filehash: None,
             metadata_blob: None,
+            platforms: Vec::new(),
+            encoding: PercentEncoding::default(),
         }
     }
 
@@ -236,12 +496,69 @@ impl AddFileOptions {
     option!(active: bool);
     option!(filehash);
     option!(metadata_blob);
+
+    /// Set the platforms the file is targeting.
+    ///
+    /// The platforms must be supported by the game.
+    #[must_use]
+    pub fn platforms(self, platforms: &[TargetPlatform]) -> Self {
+        Self {
+            platforms: platforms.to_vec(),
+            ..self
+        }
+    }
+
+    /// Set the percent-encoding of the multipart field names.
+    ///
+    /// See [`PercentEncoding`] for the available modes.
+    #[must_use]
+    pub fn percent_encoding(self, encoding: PercentEncoding) -> Self {
+        Self { encoding, ..self }
+    }
+
+    /// Don't calculate the MD5 hash of a file that is uploaded from a path.
+    #[must_use]
+    pub fn skip_hash(self) -> Self {
+        Self {
+            skip_hash: true,
+            ..self
+        }
+    }
+}
+
+/// Virus scan status and result of an active file, see [`Files::active_only`] and
+/// [`ResolvePolicy::LatestActive`].
+const ACTIVE_VIRUS_SCAN: (VirusStatus, VirusResult) = (
+    VirusStatus::SCAN_COMPLETED,
+    VirusResult::NO_THREATS_DETECTED,
+);
+
+fn is_active(file: &File) -> bool {
+    let (status, result) = ACTIVE_VIRUS_SCAN;
+    let clean = file.virus_scan.status == status && file.virus_scan.result == result;
+    let approved = file.platforms.is_empty()
+        || file
+            .platforms
+            .iter()
+            .any(|p| p.status == PlatformStatus::APPROVED);
+    clean && approved
+}
+
+async fn md5_file(path: &Path) -> io::Result<String> {
+    let file = tokio::fs::File::open(path).await?;
+    let ctx = ReaderStream::new(file)
+        .try_fold(md5::Context::new(), |mut ctx, chunk| async move {
+            ctx.consume(&chunk);
+            Ok(ctx)
+        })
+        .await?;
+    Ok(format!("{:x}", ctx.compute()))
 }
 
 #[doc(hidden)]
 impl From<AddFileOptions> for Form {
     fn from(opts: AddFileOptions) -> Form {
-        let mut form = Form::new();
+        let mut form = opts.encoding.apply(Form::new());
         if let Some(version) = opts.version {
             form = form.text("version", version);
         }
@@ -257,6 +574,9 @@ impl From<AddFileOptions> for Form {
         if let Some(metadata_blob) = opts.metadata_blob {
             form = form.text("metadata_blob", metadata_blob);
         }
+        for target in opts.platforms {
+            form = form.text("platforms[]", target.as_str().to_owned());
+        }
         form.part("filedata", opts.source.into())
     }
 }
@@ -301,3 +621,112 @@ impl Serialize for EditPlatformStatusOptions {
         s.end()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{is_active, md5_file, File, VirusResult, VirusStatus};
+    use crate::types::Timestamp;
+
+    fn file(virus_positive: u8, platforms: serde_json::Value) -> File {
+        serde_json::from_value(file_json(virus_positive, platforms)).unwrap()
+    }
+
+    fn file_json(virus_positive: u8, platforms: serde_json::Value) -> serde_json::Value {
+        serde_json::json!({
+            "id": 2,
+            "mod_id": 1,
+            "date_added": 1_499_841_487,
+            "date_scanned": 1_499_841_487,
+            "virus_status": 1,
+            "virus_positive": virus_positive,
+            "filesize": 15_181,
+            "filesize_uncompressed": 16_384,
+            "filehash": {"md5": "2d4a0e2d7273db6b0a94b0740a88ad0d"},
+            "filename": "mod.zip",
+            "version": "1.0",
+            "changelog": null,
+            "metadata_blob": null,
+            "download": {
+                "binary_url": "https://mod.io/mods/file/2/",
+                "date_expires": 1_579_316_848
+            },
+            "platforms": platforms,
+        })
+    }
+
+    #[test]
+    fn deserialize_file_without_platforms() {
+        let mut value = file_json(2, serde_json::json!([]));
+        value.as_object_mut().unwrap().remove("platforms");
+        let file: File = serde_json::from_value(value).unwrap();
+
+        assert!(file.platforms.is_empty());
+        assert_eq!(file.virus_scan.result, VirusResult::POTENTIALLY_HARMFUL);
+    }
+
+    #[test]
+    fn deserialize_file_without_optional_fields() {
+        let mut value = file_json(0, serde_json::json!([]));
+        let obj = value.as_object_mut().unwrap();
+        obj.remove("version");
+        obj.remove("changelog");
+        obj.remove("metadata_blob");
+        let file: File = serde_json::from_value(value).unwrap();
+
+        assert!(file.version.is_none());
+        assert!(file.changelog.is_none());
+        assert!(file.metadata_blob.is_none());
+    }
+
+    #[test]
+    fn serialize_file_roundtrip() {
+        let value = file_json(0, serde_json::json!([{"platform": "windows", "status": 1}]));
+        let file: File = serde_json::from_value(value.clone()).unwrap();
+
+        assert_eq!(serde_json::to_value(&file).unwrap(), value);
+    }
+
+    #[test]
+    fn download_url_expired() {
+        let file = file(0, serde_json::json!([]));
+
+        assert!(!file.is_download_url_expired(Timestamp::from_secs(1_579_316_847)));
+        assert!(file.is_download_url_expired(Timestamp::from_secs(1_579_316_848)));
+        assert!(file.is_download_url_expired(Timestamp::from_secs(1_579_316_849)));
+    }
+
+    #[test]
+    fn active_files() {
+        use serde_json::json;
+
+        assert!(is_active(&file(0, json!([]))));
+        assert!(is_active(&file(
+            0,
+            json!([{"platform": "windows", "status": 0}, {"platform": "linux", "status": 1}])
+        )));
+        assert!(!is_active(&file(
+            0,
+            json!([{"platform": "windows", "status": 2}])
+        )));
+        assert!(!is_active(&file(1, json!([]))));
+
+        let mut unscanned = file(0, json!([]));
+        unscanned.virus_scan.status = VirusStatus::NOT_SCANNED;
+        assert!(!is_active(&unscanned));
+        assert!(!is_active(&file(
+            2,
+            json!([{"platform": "windows", "status": 1}])
+        )));
+    }
+
+    #[tokio::test]
+    async fn md5_of_file() {
+        let path = std::env::temp_dir().join(format!("modio-md5-{}.txt", std::process::id()));
+        std::fs::write(&path, "hello world").unwrap();
+
+        let hash = md5_file(&path).await;
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(hash.unwrap(), "5eb63bbbe01eeed093cb22bb8f5acdc3");
+    }
+}
@@ -1,4 +1,34 @@
 //! Filtering and sorting
+//!
+//! Every endpoint that supports filtering has its own `filters` module, e.g. [`mods::filters`],
+//! with the fields that can be used as filters. Fields that can be used for sorting implement
+//! the [`OrderBy`] trait, so only documented sort keys are available.
+//!
+//! ```
+//! use modio::filter::prelude::*;
+//! use modio::mods::filters::{Downloads, Tags};
+//!
+//! let filter = Tags::eq("Weapons").order_by(Downloads::desc()).limit(20);
+//! ```
+//!
+//! # Alternatives and negation
+//!
+//! Filters are always combined with `AND` and every filter key is sent only once, combining
+//! the same field and operator twice keeps the last value. mod.io has no `OR` operator across
+//! filters, alternative values of a field are matched with [`In`] and excluded with the
+//! negated operators of [`NotEq`], [`NotIn`] and [`NotLike`].
+//!
+//! ```
+//! use modio::filter::prelude::*;
+//!
+//! // id-in=1,2
+//! let filter = Id::_in(vec![1, 2]);
+//!
+//! // name_id-not-in=foo,bar&name_id-not-lk=baz*
+//! let filter = NameId::not_in(vec!["foo", "bar"]).and(NameId::not_like("baz*"));
+//! ```
+//!
+//! [`mods::filters`]: crate::mods::filters
 use std::collections::BTreeSet;
 use std::fmt;
 
@@ -316,10 +346,17 @@ pub trait OrderBy: sealed::FilterPriv {
 
 /// Create a custom `Filter`.
 ///
+/// This can also be used for query parameters that are not modeled by the typed filters yet.
+/// With [`Operator::Equals`] the name is used as-is for the query parameter.
+///
 /// ```
+/// use modio::filter::prelude::*;
 /// use modio::filter::{custom_filter, Operator};
 ///
 /// let filter = custom_filter("foo", Operator::Equals, "bar");
+///
+/// // Merged with the typed filters into `?foo=bar&id-in=1,2`
+/// let filter = Id::_in(vec![1, 2]).and(filter);
 /// ```
 pub fn custom_filter<S, T, V>(name: S, op: Operator, value: V) -> Filter
 where
@@ -355,7 +392,7 @@ pub fn custom_order_by_desc<S: Into<String>>(name: S) -> Filter {
 #[derive(Clone, Default)]
 pub struct Filter {
     filters: BTreeSet<FilterEntry>,
-    order_by: Option<Sorting>,
+    order_by: Vec<Sorting>,
     limit: Option<usize>,
     offset: Option<usize>,
 }
@@ -380,7 +417,7 @@ impl Filter {
         S: Into<String>,
     {
         Filter {
-            order_by: Some(Sorting::Asc(name.into())),
+            order_by: vec![Sorting::Asc(name.into())],
             ..Default::default()
         }
     }
@@ -390,7 +427,7 @@ impl Filter {
         S: Into<String>,
     {
         Filter {
-            order_by: Some(Sorting::Desc(name.into())),
+            order_by: vec![Sorting::Desc(name.into())],
             ..Default::default()
         }
     }
@@ -409,13 +446,23 @@ impl Filter {
         }
     }
 
+    /// Combines the filters of `self` and `other`.
+    ///
+    /// A filter of `other` replaces the filter of `self` with the same field and operator.
     #[must_use]
-    pub fn and(self, mut other: Filter) -> Filter {
+    pub fn and(self, other: Filter) -> Filter {
         let Filter { mut filters, .. } = self;
-        filters.append(&mut other.filters);
+        for filter in other.filters {
+            filters.replace(filter);
+        }
+        let order_by = if other.order_by.is_empty() {
+            self.order_by
+        } else {
+            other.order_by
+        };
         Filter {
             filters,
-            order_by: other.order_by.or(self.order_by),
+            order_by,
             limit: other.limit.or(self.limit),
             offset: other.offset.or(self.offset),
         }
@@ -423,12 +470,30 @@ impl Filter {
 
     #[must_use]
     pub fn order_by(self, other: Filter) -> Filter {
+        if other.order_by.is_empty() {
+            return self;
+        }
         Filter {
-            order_by: other.order_by.or(self.order_by),
+            order_by: other.order_by,
             ..self
         }
     }
 
+    /// Add the sort keys of `other` as secondary sort keys.
+    ///
+    /// ```
+    /// use modio::filter::prelude::*;
+    /// use modio::mods::filters::{DateUpdated, Popular};
+    ///
+    /// let filter = Popular::desc().then_by(DateUpdated::asc());
+    /// assert_eq!(filter.to_query_string(), "_sort=-popular%2Cdate_updated");
+    /// ```
+    #[must_use]
+    pub fn then_by(mut self, other: Filter) -> Filter {
+        self.order_by.extend(other.order_by);
+        self
+    }
+
     #[must_use]
     pub fn limit(self, limit: usize) -> Filter {
         Filter {
@@ -444,8 +509,131 @@ impl Filter {
             ..self
         }
     }
+
+    /// Parse a `Filter` from an url-encoded query string.
+    ///
+    /// The query string format is the same as produced by [`Filter::to_query_string`], the
+    /// `_limit`, `_offset` and `_sort` parameters are restored as well. Repeated filter keys
+    /// keep the last value like combining filters with [`Filter::and`].
+    ///
+    /// ```
+    /// use modio::filter::prelude::*;
+    /// use modio::mods::filters::{Downloads, Tags};
+    ///
+    /// let filter = Tags::_in(vec!["Weapons", "Armor"]).order_by(Downloads::desc()).limit(20);
+    ///
+    /// let query = filter.to_query_string();
+    /// assert_eq!(query, "tags-in=Weapons%2CArmor&_limit=20&_sort=-downloads");
+    ///
+    /// let filter = Filter::parse(&query)?;
+    /// assert_eq!(filter.to_query_string(), query);
+    /// # Ok::<_, modio::filter::ParseFilterError>(())
+    /// ```
+    pub fn parse(query: &str) -> Result<Filter, ParseFilterError> {
+        let mut filter = Filter::default();
+        for (key, value) in url::form_urlencoded::parse(query.as_bytes()) {
+            match &*key {
+                "_limit" => {
+                    let limit = value.parse().map_err(|_| ParseFilterError::new(&key))?;
+                    filter.limit = Some(limit);
+                }
+                "_offset" => {
+                    let offset = value.parse().map_err(|_| ParseFilterError::new(&key))?;
+                    filter.offset = Some(offset);
+                }
+                "_sort" => {
+                    filter.order_by = value
+                        .split(',')
+                        .map(|key| match key.strip_prefix('-') {
+                            Some(field) if !field.is_empty() => Some(Sorting::Desc(field.into())),
+                            None if !key.is_empty() => Some(Sorting::Asc(key.into())),
+                            _ => None,
+                        })
+                        .collect::<Option<_>>()
+                        .ok_or_else(|| ParseFilterError::new(&key))?;
+                }
+                "" => return Err(ParseFilterError::new(&key)),
+                _ => {
+                    let (name, op) = Operator::split_key(&key);
+                    let value = match op {
+                        Operator::In | Operator::NotIn => {
+                            OneOrMany::Many(value.split(',').map(ToOwned::to_owned).collect())
+                        }
+                        _ => OneOrMany::One(value.into_owned()),
+                    };
+                    filter
+                        .filters
+                        .replace(FilterEntry::new(name.to_owned(), op, value));
+                }
+            }
+        }
+        Ok(filter)
+    }
+
+    /// Returns the filter as url-encoded query string.
+    ///
+    /// The query string can be parsed again with [`Filter::parse`].
+    pub fn to_query_string(&self) -> String {
+        let mut query = url::form_urlencoded::Serializer::new(String::new());
+        for f in &self.filters {
+            let value = match f.value {
+                OneOrMany::One(ref v) => v.clone(),
+                OneOrMany::Many(ref v) => v.join(","),
+            };
+            query.append_pair(&format!("{}{}", f.name, f.op), &value);
+        }
+        if let Some(limit) = self.limit {
+            query.append_pair("_limit", &limit.to_string());
+        }
+        if let Some(offset) = self.offset {
+            query.append_pair("_offset", &offset.to_string());
+        }
+        if let Some(sort) = self.sort() {
+            query.append_pair("_sort", &sort);
+        }
+        query.finish()
+    }
+
+    /// Returns the comma-separated sort keys of the `_sort` parameter.
+    fn sort(&self) -> Option<String> {
+        if self.order_by.is_empty() {
+            return None;
+        }
+        let keys = self.order_by.iter().map(ToString::to_string);
+        Some(keys.collect::<Vec<_>>().join(","))
+    }
+}
+
+impl std::str::FromStr for Filter {
+    type Err = ParseFilterError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Filter::parse(s)
+    }
+}
+
+/// Error returned by [`Filter::parse`] for an invalid query parameter.
+#[derive(Debug)]
+pub struct ParseFilterError {
+    key: String,
+}
+
+impl ParseFilterError {
+    fn new(key: &str) -> Self {
+        Self {
+            key: key.to_owned(),
+        }
+    }
 }
 
+impl fmt::Display for ParseFilterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid filter parameter {:?}", self.key)
+    }
+}
+
+impl std::error::Error for ParseFilterError {}
+
 impl std::ops::Add for Filter {
     type Output = Self;
 
@@ -488,7 +676,7 @@ impl serde::ser::Serialize for Filter {
         let len = self.filters.len()
             + self.limit.as_ref().map(|_| 1).unwrap_or_default()
             + self.offset.as_ref().map(|_| 1).unwrap_or_default()
-            + self.order_by.as_ref().map(|_| 1).unwrap_or_default();
+            + usize::from(!self.order_by.is_empty());
 
         let mut map = serializer.serialize_map(Some(len))?;
         for (k, v) in self.filters.iter().map(map_filters) {
@@ -500,8 +688,8 @@ impl serde::ser::Serialize for Filter {
         if let Some(ref offset) = self.offset {
             map.serialize_entry("_offset", offset)?;
         }
-        if let Some(ref order_by) = self.order_by {
-            map.serialize_entry("_sort", &order_by.to_string())?;
+        if let Some(sort) = self.sort() {
+            map.serialize_entry("_sort", &sort)?;
         }
         map.end()
     }
@@ -609,6 +797,30 @@ impl fmt::Display for Operator {
     }
 }
 
+impl Operator {
+    /// Splits a query key like `name_id-not-lk` into the field name and the operator.
+    fn split_key(key: &str) -> (&str, Operator) {
+        const SUFFIXES: [(&str, Operator); 10] = [
+            ("-bitwise-and", Operator::BitwiseAnd),
+            ("-not-lk", Operator::NotLike),
+            ("-not-in", Operator::NotIn),
+            ("-not", Operator::Not),
+            ("-lk", Operator::Like),
+            ("-in", Operator::In),
+            ("-min", Operator::Min),
+            ("-max", Operator::Max),
+            ("-st", Operator::SmallerThan),
+            ("-gt", Operator::GreaterThan),
+        ];
+        for (suffix, op) in SUFFIXES {
+            if let Some(name) = key.strip_suffix(suffix) {
+                return (name, op);
+            }
+        }
+        (key, Operator::Equals)
+    }
+}
+
 /// Represents a value or a list of values of a filter.
 #[derive(Clone, Debug)]
 pub enum OneOrMany<T>
@@ -694,6 +906,21 @@ mod test {
         assert_eq!(f.to_string(), r#"{"_offset":20}"#);
     }
 
+    #[test]
+    fn and_replaces_same_key() {
+        use super::prelude::*;
+
+        filter!(GameId, GAME_ID, "game_id", Eq, NotEq, In);
+
+        let f = GameId::eq(1).and(GameId::eq(2));
+        assert_eq!(f.to_string(), r#"{"game_id":"2"}"#);
+
+        let f = GameId::_in(vec![1, 2])
+            .and(GameId::ne(3))
+            .and(GameId::_in(vec![4]));
+        assert_eq!(f.to_string(), r#"{"game_id-not":"3","game_id-in":"4"}"#);
+    }
+
     #[test]
     fn custom_filters() {
         use super::prelude::*;
@@ -706,6 +933,13 @@ mod test {
 
         let f = custom_order_by_asc("foo");
         assert_eq!(f.to_string(), r#"{"_sort":"foo"}"#);
+
+        let f = custom_filter("foo", Operator::Equals, "bar").and(custom_filter(
+            "foo",
+            Operator::Equals,
+            "baz",
+        ));
+        assert_eq!(f.to_string(), r#"{"foo":"baz"}"#);
     }
 
     #[test]
@@ -718,6 +952,80 @@ mod test {
         let f = Id::eq(1) + NameId::eq("foo");
         assert_eq!(f.to_string(), r#"{"id":"1","name_id":"foo"}"#);
     }
+
+    #[test]
+    fn parse_query_string() {
+        use super::prelude::*;
+        use super::*;
+
+        let f = Id::_in(vec![1, 2])
+            .and(NameId::not_like("foo*"))
+            .and(custom_filter("maturity_option", Operator::BitwiseAnd, 3))
+            .order_by(NameId::desc())
+            .limit(10)
+            .offset(20);
+        let query = f.to_query_string();
+        assert_eq!(
+            query,
+            "id-in=1%2C2&maturity_option-bitwise-and=3&name_id-not-lk=foo*&_limit=10&_offset=20&_sort=-name_id"
+        );
+
+        let parsed = Filter::parse(&query).unwrap();
+        assert_eq!(parsed.to_query_string(), query);
+        assert_eq!(parsed.to_string(), f.to_string());
+
+        let f: Filter = "id=1&id=2&name_id-not-in=a,b&_sort=name_id"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            f.to_string(),
+            r#"{"id":"2","name_id-not-in":"a,b","_sort":"name_id"}"#
+        );
+
+        assert!(Filter::parse("_limit=ten").is_err());
+        assert!(Filter::parse("_sort=-").is_err());
+        assert!(Filter::parse("=1").is_err());
+        assert!(Filter::parse("").unwrap().to_query_string().is_empty());
+    }
+
+    #[test]
+    fn multiple_sort_keys() {
+        use super::prelude::*;
+
+        filter!(Popular, POPULAR, "popular", OrderBy);
+        filter!(DateUpdated, DATE_UPDATED, "date_updated", OrderBy);
+
+        let f = Popular::desc().then_by(DateUpdated::asc());
+        assert_eq!(f.to_string(), r#"{"_sort":"-popular,date_updated"}"#);
+        assert_eq!(f.to_query_string(), "_sort=-popular%2Cdate_updated");
+
+        let f = Id::eq(1)
+            .order_by(Popular::desc().then_by(DateUpdated::asc()))
+            .and(NameId::eq("foo"));
+        assert_eq!(
+            f.to_string(),
+            r#"{"id":"1","name_id":"foo","_sort":"-popular,date_updated"}"#
+        );
+
+        // `order_by` replaces all sort keys
+        let f = f.order_by(Id::asc());
+        assert_eq!(f.to_string(), r#"{"id":"1","name_id":"foo","_sort":"id"}"#);
+
+        let f = Filter::parse("_sort=-popular,date_updated").unwrap();
+        assert_eq!(f.to_string(), r#"{"_sort":"-popular,date_updated"}"#);
+        assert!(Filter::parse("_sort=-popular,").is_err());
+    }
+
+    #[test]
+    fn alternatives_and_negation() {
+        use super::prelude::*;
+
+        let f = Id::_in(vec![1, 2]);
+        assert_eq!(f.to_string(), r#"{"id-in":"1,2"}"#);
+
+        let f = Id::not_in(vec![1, 2]).and(Id::ne(3));
+        assert_eq!(f.to_string(), r#"{"id-not":"3","id-not-in":"1,2"}"#);
+    }
 }
 
 // vim: fdm=marker
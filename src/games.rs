@@ -2,6 +2,8 @@
 use std::ffi::OsStr;
 use std::path::Path;
 
+use bytes::Bytes;
+use futures_util::TryStreamExt;
 use mime::IMAGE_STAR;
 
 use crate::file_source::FileSource;
@@ -21,26 +23,70 @@ pub use crate::types::Status;
 #[derive(Clone)]
 pub struct Games {
     modio: Modio,
+    show_hidden_tags: Option<bool>,
 }
 
 impl Games {
     pub(crate) fn new(modio: Modio) -> Self {
-        Self { modio }
+        Self {
+            modio,
+            show_hidden_tags: None,
+        }
+    }
+
+    /// Include hidden tags in the returned games.
+    ///
+    /// Hidden tags are only returned for authenticated users with the permission to see them.
+    #[must_use]
+    pub fn show_hidden_tags(mut self, value: bool) -> Self {
+        self.show_hidden_tags = Some(value);
+        self
     }
 
     /// Returns a `Query` interface to retrieve games.
     ///
+    /// The request only needs the api key, the access token of the client is not sent. The
+    /// games returned are the ones mod.io makes visible for the api key. Use
+    /// [`Me::games`](crate::user::Me::games) for the games the authenticated user added or is
+    /// a team member of.
+    ///
     /// See [Filters and sorting](filters).
     pub fn search(&self, filter: Filter) -> Query<Game> {
         let route = Route::GetGames {
-            show_hidden_tags: None,
+            show_hidden_tags: self.show_hidden_tags,
         };
         Query::new(self.modio.clone(), route, filter)
     }
 
     /// Return a reference to a game.
     pub fn get(&self, id: GameId) -> GameRef {
-        GameRef::new(self.modio.clone(), id)
+        GameRef {
+            modio: self.modio.clone(),
+            id,
+            show_hidden_tags: self.show_hidden_tags,
+        }
+    }
+
+    /// Return the game with the given `name_id` or `None` if no game is found.
+    ///
+    /// The `name_id` is the path segment of the game profile url, see
+    /// [`parse_mod_url`](crate::parse_mod_url).
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let modio = modio::Modio::new("api-key")?;
+    /// if let Some(game) = modio.games().get_by_name_id("openxcom").await? {
+    ///     println!("{}: {}", game.id, game.name);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn get_by_name_id<S: Into<String>>(&self, name_id: S) -> Result<Option<Game>> {
+        use crate::filter::Eq;
+        self.search(filters::NameId::eq(name_id.into()))
+            .first()
+            .await
     }
 }
 
@@ -49,18 +95,32 @@ impl Games {
 pub struct GameRef {
     modio: Modio,
     id: GameId,
+    show_hidden_tags: Option<bool>,
 }
 
 impl GameRef {
     pub(crate) fn new(modio: Modio, id: GameId) -> Self {
-        Self { modio, id }
+        Self {
+            modio,
+            id,
+            show_hidden_tags: None,
+        }
+    }
+
+    /// Include hidden tags in the returned game and its tag options.
+    ///
+    /// Hidden tags are only returned for authenticated users with the permission to see them.
+    #[must_use]
+    pub fn show_hidden_tags(mut self, value: bool) -> Self {
+        self.show_hidden_tags = Some(value);
+        self
     }
 
     /// Get a reference to the Modio game object that this `GameRef` refers to.
     pub async fn get(self) -> Result<Game> {
         let route = Route::GetGame {
             id: self.id,
-            show_hidden_tags: None,
+            show_hidden_tags: self.show_hidden_tags,
         };
         self.modio.request(route).send().await
     }
@@ -83,7 +143,11 @@ impl GameRef {
 
     /// Return a reference to an interface that provides access to the tags of a game.
     pub fn tags(&self) -> Tags {
-        Tags::new(self.modio.clone(), self.id)
+        Tags {
+            modio: self.modio.clone(),
+            game_id: self.id,
+            show_hidden_tags: self.show_hidden_tags,
+        }
     }
 
     /// Add new media to a game. [required: token]
@@ -103,17 +167,24 @@ impl GameRef {
 pub struct Tags {
     modio: Modio,
     game_id: GameId,
+    show_hidden_tags: Option<bool>,
 }
 
 impl Tags {
-    fn new(modio: Modio, game_id: GameId) -> Self {
-        Self { modio, game_id }
+    /// Include hidden tag options.
+    ///
+    /// Hidden tags are only returned for authenticated users with the permission to see them.
+    #[must_use]
+    pub fn show_hidden_tags(mut self, value: bool) -> Self {
+        self.show_hidden_tags = Some(value);
+        self
     }
 
     /// List tag options.
     pub async fn list(self) -> Result<Vec<TagOption>> {
         let route = Route::GetGameTags {
             game_id: self.game_id,
+            show_hidden_tags: self.show_hidden_tags,
         };
         Query::new(self.modio, route, Filter::default())
             .collect()
@@ -125,11 +196,34 @@ impl Tags {
     pub async fn iter(self) -> Result<impl Stream<Item = Result<TagOption>>> {
         let route = Route::GetGameTags {
             game_id: self.game_id,
+            show_hidden_tags: self.show_hidden_tags,
         };
         let filter = Filter::default();
         Query::new(self.modio, route, filter).iter().await
     }
 
+    /// Return the tag option group with the given name.
+    ///
+    /// All tag options of the game are fetched and the group is selected afterwards
+    /// by comparing its `name`.
+    pub async fn group(self, name: &str) -> Result<Option<TagOption>> {
+        let route = Route::GetGameTags {
+            game_id: self.game_id,
+            show_hidden_tags: self.show_hidden_tags,
+        };
+        let filter = Filter::default();
+        let mut it = Query::<TagOption>::new(self.modio, route, filter)
+            .iter()
+            .await?;
+
+        while let Some(option) = it.try_next().await? {
+            if option.name == name {
+                return Ok(Some(option));
+            }
+        }
+        Ok(None)
+    }
+
     /// Add tag options. [required: token]
     #[allow(clippy::should_implement_trait)]
     pub async fn add(self, options: AddTagsOptions) -> Result<()> {
@@ -209,6 +303,22 @@ impl Tags {
 ///
 /// let filter = Id::_in(vec![1, 2]).order_by(Id::desc());
 /// ```
+///
+/// The option filters support the `bit_and` operator to check for flags.
+///
+/// ```
+/// use modio::filter::prelude::*;
+/// use modio::games::filters::{ApiAccessOptions, CommunityOptions};
+/// use modio::types::games;
+///
+/// let filter = ApiAccessOptions::bit_and(games::ApiAccessOptions::ALLOW_DIRECT_DOWNLOAD.bits())
+///     .and(CommunityOptions::bit_and(games::CommunityOptions::DISCUSSIONS.bits()));
+/// ```
+///
+/// There is no filter for the supported platforms of a game, use [`Game::platforms`] of the
+/// returned games instead.
+///
+/// [`Game::platforms`]: crate::types::games::Game::platforms
 #[rustfmt::skip]
 pub mod filters {
     #[doc(inline)]
@@ -370,6 +480,23 @@ impl EditMediaOptions {
         }
     }
 
+    /// Set the logo from memory instead of a file.
+    #[must_use]
+    pub fn logo_bytes<B, S>(self, logo: B, filename: S) -> Self
+    where
+        B: Into<Bytes>,
+        S: Into<String>,
+    {
+        Self {
+            logo: Some(FileSource::new_from_bytes(
+                logo.into(),
+                filename.into(),
+                IMAGE_STAR,
+            )),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn icon<P: AsRef<Path>>(self, icon: P) -> Self {
         let icon = icon.as_ref();
@@ -384,6 +511,23 @@ impl EditMediaOptions {
         }
     }
 
+    /// Set the icon from memory instead of a file.
+    #[must_use]
+    pub fn icon_bytes<B, S>(self, icon: B, filename: S) -> Self
+    where
+        B: Into<Bytes>,
+        S: Into<String>,
+    {
+        Self {
+            icon: Some(FileSource::new_from_bytes(
+                icon.into(),
+                filename.into(),
+                IMAGE_STAR,
+            )),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn header<P: AsRef<Path>>(self, header: P) -> Self {
         let header = header.as_ref();
@@ -397,6 +541,23 @@ impl EditMediaOptions {
             ..self
         }
     }
+
+    /// Set the header from memory instead of a file.
+    #[must_use]
+    pub fn header_bytes<B, S>(self, header: B, filename: S) -> Self
+    where
+        B: Into<Bytes>,
+        S: Into<String>,
+    {
+        Self {
+            header: Some(FileSource::new_from_bytes(
+                header.into(),
+                filename.into(),
+                IMAGE_STAR,
+            )),
+            ..self
+        }
+    }
 }
 
 #[doc(hidden)]
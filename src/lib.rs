@@ -152,10 +152,13 @@ mod request;
 mod routing;
 
 pub use crate::auth::Credentials;
-pub use crate::client::{Builder, Modio};
+pub use crate::client::{Builder, Modio, RateLimit};
 pub use crate::download::DownloadAction;
 pub use crate::error::{Error, Result};
+pub use crate::file_source::PercentEncoding;
 pub use crate::loader::{Page, Query};
+pub use crate::mods::parse_mod_url;
+pub use crate::request::RawRequest;
 pub use crate::types::{Deletion, Editing, TargetPlatform, TargetPortal};
 
 mod prelude {
@@ -178,5 +181,5 @@ pub mod lib {
     pub use reqwest::ClientBuilder;
     #[cfg(feature = "__tls")]
     pub use reqwest::{Certificate, Identity};
-    pub use reqwest::{Proxy, Url};
+    pub use reqwest::{Method, Proxy, Url};
 }
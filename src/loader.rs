@@ -5,6 +5,7 @@ use std::task::{Context, Poll};
 use futures_util::future::Either;
 use futures_util::{stream, Stream, StreamExt, TryStreamExt};
 use pin_project_lite::pin_project;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use serde::de::DeserializeOwned;
 
 use crate::filter::Filter;
@@ -17,18 +18,68 @@ pub struct Query<T> {
     modio: Modio,
     route: Route,
     filter: Filter,
+    headers: HeaderMap,
     phantom: PhantomData<T>,
 }
 
+impl<T> Clone for Query<T> {
+    fn clone(&self) -> Self {
+        Self {
+            modio: self.modio.clone(),
+            route: self.route,
+            filter: self.filter.clone(),
+            headers: self.headers.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
 impl<T> Query<T> {
     pub(crate) fn new(modio: Modio, route: Route, filter: Filter) -> Self {
+        let filter = match modio.inner.default_limit {
+            Some(limit) => Filter::with_limit(limit).and(filter),
+            None => filter,
+        };
         Self {
             modio,
             route,
             filter,
+            headers: HeaderMap::new(),
             phantom: PhantomData,
         }
     }
+
+    /// Add a header to the requests of the search.
+    ///
+    /// The header is sent with the request of every page and replaces a default header of
+    /// the client with the same name.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use modio::filter::Filter;
+    /// use modio::lib::header::{HeaderName, HeaderValue};
+    /// use modio::types::id::Id;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let modio = modio::Modio::new("api-key")?;
+    ///
+    /// let mods = modio
+    ///     .game(Id::new(51))
+    ///     .mods()
+    ///     .search(Filter::default())
+    ///     .header(
+    ///         HeaderName::from_static("x-modio-platform"),
+    ///         HeaderValue::from_static("linux"),
+    ///     )
+    ///     .collect()
+    ///     .await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
 }
 
 impl<T: DeserializeOwned + Send> Query<T> {
@@ -89,7 +140,7 @@ impl<T: DeserializeOwned + Send> Query<T> {
     /// ```
     #[allow(clippy::iter_not_returning_iterator)]
     pub async fn iter(self) -> Result<impl Stream<Item = Result<T>>> {
-        let (st, (total, _)) = stream(self.modio, self.route, self.filter).await?;
+        let (st, (total, _)) = stream(self.modio, self.route, self.filter, self.headers).await?;
         let st = st
             .map_ok(|list| stream::iter(list.into_iter().map(Ok)))
             .try_flatten();
@@ -127,7 +178,8 @@ impl<T: DeserializeOwned + Send> Query<T> {
     /// # }
     /// ```
     pub async fn paged(self) -> Result<impl Stream<Item = Result<Page<T>>>> {
-        let (st, (total, limit)) = stream(self.modio, self.route, self.filter).await?;
+        let (st, (total, limit)) =
+            stream(self.modio, self.route, self.filter, self.headers).await?;
         let size_hint = if total == 0 {
             0
         } else {
@@ -141,6 +193,7 @@ async fn stream<T>(
     modio: Modio,
     route: Route,
     filter: Filter,
+    headers: HeaderMap,
 ) -> Result<(impl Stream<Item = Result<Page<T>>>, (u32, u32))>
 where
     T: DeserializeOwned + Send,
@@ -153,6 +206,7 @@ where
     let list = modio
         .request(route)
         .query(&filter)
+        .headers(headers.clone())
         .send::<List<T>>()
         .await?;
 
@@ -161,7 +215,7 @@ where
         limit: list.limit,
         remaining: list.total - list.count,
     };
-    let initial = (modio, route, filter, state);
+    let initial = (modio, route, filter, headers, state);
     let stats = (list.total, list.limit);
     if list.total == 0 {
         return Ok((Either::Left(stream::empty()), stats));
@@ -169,32 +223,37 @@ where
 
     let first = stream::once(async { Ok::<_, crate::Error>(Page(list)) });
 
-    let others = stream::try_unfold(initial, |(modio, route, filter, state)| async move {
-        if let State { remaining: 0, .. } = state {
-            return Ok(None);
-        }
-        let filter = filter.offset((state.offset + state.limit) as usize);
-        let remaining = state.remaining;
-
-        let list = modio
-            .request(route)
-            .query(&filter)
-            .send::<List<T>>()
-            .await?;
-
-        let state = (
-            modio,
-            route,
-            filter,
-            State {
-                offset: list.offset,
-                limit: list.limit,
-                remaining: remaining - list.count,
-            },
-        );
-
-        Ok(Some((Page(list), state)))
-    });
+    let others = stream::try_unfold(
+        initial,
+        |(modio, route, filter, headers, state)| async move {
+            if let State { remaining: 0, .. } = state {
+                return Ok(None);
+            }
+            let filter = filter.offset((state.offset + state.limit) as usize);
+            let remaining = state.remaining;
+
+            let list = modio
+                .request(route)
+                .query(&filter)
+                .headers(headers.clone())
+                .send::<List<T>>()
+                .await?;
+
+            let state = (
+                modio,
+                route,
+                filter,
+                headers,
+                State {
+                    offset: list.offset,
+                    limit: list.limit,
+                    remaining: remaining - list.count,
+                },
+            );
+
+            Ok(Some((Page(list), state)))
+        },
+    );
 
     Ok((Either::Right(first.chain(others)), stats))
 }
@@ -47,30 +47,52 @@ impl Metadata {
     }
 
     /// Add metadata for a mod that this `Metadata` refers to.
+    ///
+    /// Every value of a key is submitted as a separate `metadata[]=key:value` pair,
+    /// so a map returned by [`Metadata::get`] can be modified and written back.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use modio::types::id::Id;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let modio = modio::Modio::new(("api-key", "token"))?;
+    ///
+    /// let metadata = modio.mod_(Id::new(5), Id::new(19)).metadata();
+    /// metadata.add([("pistol-dmg", "800"), ("smg-dmg", "1200")]).await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
     #[allow(clippy::should_implement_trait)]
-    pub async fn add(self, metadata: MetadataMap) -> Result<()> {
+    pub async fn add<M: Into<MetadataMap>>(self, metadata: M) -> Result<()> {
         let route = Route::AddModMetadata {
             game_id: self.game,
             mod_id: self.mod_id,
         };
         self.modio
             .request(route)
-            .form(&metadata)
+            .form(&metadata.into())
             .send::<Message>()
             .await?;
         Ok(())
     }
 
     /// Delete metadata for a mod that this `Metadata` refers to.
-    pub async fn delete(self, metadata: MetadataMap) -> Result<Deletion> {
+    pub async fn delete<M: Into<MetadataMap>>(self, metadata: M) -> Result<Deletion> {
         let route = Route::DeleteModMetadata {
             game_id: self.game,
             mod_id: self.mod_id,
         };
-        self.modio.request(route).form(&metadata).send().await
+        self.modio
+            .request(route)
+            .form(&metadata.into())
+            .send()
+            .await
     }
 }
 
+/// Serialize a `MetadataMap` as `metadata[]` form entries.
+///
+/// A key without values is serialized as `metadata[]=key`.
 #[doc(hidden)]
 impl serde::ser::Serialize for MetadataMap {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -92,3 +114,62 @@ impl serde::ser::Serialize for MetadataMap {
         map.end()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{assert_ser_tokens, Token};
+
+    use super::MetadataMap;
+
+    #[test]
+    fn serialize_metadata_map() {
+        let mut map = MetadataMap::new();
+        map.insert(
+            "pistol-dmg".to_owned(),
+            vec!["800".to_owned(), "850".to_owned()],
+        );
+
+        assert_ser_tokens(
+            &map,
+            &[
+                Token::Map { len: Some(2) },
+                Token::Str("metadata[]"),
+                Token::Str("pistol-dmg:800"),
+                Token::Str("metadata[]"),
+                Token::Str("pistol-dmg:850"),
+                Token::MapEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn serialize_metadata_key_without_values() {
+        let mut map = MetadataMap::new();
+        map.insert("smg-dmg".to_owned(), vec![]);
+
+        assert_ser_tokens(
+            &map,
+            &[
+                Token::Map { len: Some(1) },
+                Token::Str("metadata[]"),
+                Token::Str("smg-dmg"),
+                Token::MapEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn metadata_map_from_pairs() {
+        let map = MetadataMap::from([("pistol-dmg", "800"), ("pistol-dmg", "850")]);
+        assert_eq!(map["pistol-dmg"], ["800", "850"]);
+
+        let pairs = [("smg-dmg".to_owned(), "1200".to_owned())];
+        let map = MetadataMap::from(&pairs[..]);
+        assert_eq!(map["smg-dmg"], ["1200"]);
+
+        let map = (1..=2)
+            .map(|i| ("level", i.to_string()))
+            .collect::<MetadataMap>();
+        assert_eq!(map["level"], ["1", "2"]);
+    }
+}
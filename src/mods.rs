@@ -1,7 +1,9 @@
 //! Mods Interface
+use std::collections::{BTreeMap, HashSet};
 use std::ffi::OsStr;
 use std::path::Path;
 
+use bytes::Bytes;
 use mime::{APPLICATION_OCTET_STREAM, IMAGE_STAR};
 use url::Url;
 
@@ -12,6 +14,8 @@ use crate::metadata::Metadata;
 use crate::prelude::*;
 use crate::teams::Members;
 use crate::types::id::{FileId, GameId, ModId};
+use crate::types::{ErrorRef, Timestamp};
+use crate::PercentEncoding;
 
 pub use crate::types::mods::{
     CommunityOptions, Dependency, Event, EventType, Image, MaturityOption, Media, Mod, Platform,
@@ -40,11 +44,82 @@ impl Mods {
         Query::new(self.modio.clone(), route, filter)
     }
 
+    /// Returns a `Query` interface to retrieve the mods matching the full-text search `query`.
+    ///
+    /// This is a shortcut for `search(Fulltext::eq(query))`, use [`Mods::search`] with
+    /// [`filters::Fulltext`] to combine the search with other filters, sorting and limits.
+    ///
+    /// ```no_run
+    /// use futures_util::TryStreamExt;
+    /// use modio::types::id::Id;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let modio = modio::Modio::new("api-key")?;
+    ///
+    /// let mut st = modio.game(Id::new(5)).mods().search_text("balance").iter().await?;
+    /// while let Some(mod_) = st.try_next().await? {
+    ///     println!("{}: {}", mod_.id, mod_.name);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn search_text<S: Into<String>>(&self, query: S) -> Query<Mod> {
+        use crate::filter::Eq;
+        self.search(filters::Fulltext::eq(query.into()))
+    }
+
+    /// Returns a `Query` interface to retrieve the mods that were updated after `since`,
+    /// most recently updated first.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures_util::TryStreamExt;
+    /// use modio::types::id::Id;
+    /// # async fn run(since: modio::types::Timestamp) -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let modio = modio::Modio::new("api-key")?;
+    ///
+    /// let mut st = modio.game(Id::new(5)).mods().updated_since(since).iter().await?;
+    /// while let Some(mod_) = st.try_next().await? {
+    ///     println!("{} was updated at {}", mod_.name, mod_.date_updated.as_secs());
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn updated_since(&self, since: Timestamp) -> Query<Mod> {
+        use crate::filter::{Cmp, OrderBy};
+        use filters::DateUpdated;
+
+        self.search(DateUpdated::gt(since.as_secs()).order_by(DateUpdated::desc()))
+    }
+
     /// Return a reference to a mod.
     pub fn get(&self, id: ModId) -> ModRef {
         ModRef::new(self.modio.clone(), self.game, id)
     }
 
+    /// Return the mod with the given `name_id` or `None` if no mod is found.
+    ///
+    /// The `name_id` is the path segment of the mod profile url, see [`parse_mod_url`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// use modio::types::id::Id;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let modio = modio::Modio::new("api-key")?;
+    ///
+    /// let mods = modio.game(Id::new(51)).mods();
+    /// if let Some(mod_) = mods.get_by_name_id("the-x-com-files").await? {
+    ///     println!("{}: {}", mod_.id, mod_.name);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn get_by_name_id<S: Into<String>>(&self, name_id: S) -> Result<Option<Mod>> {
+        use crate::filter::Eq;
+        self.search(filters::NameId::eq(name_id.into()))
+            .first()
+            .await
+    }
+
     /// Add a mod and return the newly created Modio mod object. [required: token]
     #[allow(clippy::should_implement_trait)]
     pub async fn add(self, options: AddModOptions) -> Result<Mod> {
@@ -64,6 +139,40 @@ impl Mods {
         Query::new(self.modio, route, filter)
     }
 
+    /// Returns the statistics of the given mods.
+    ///
+    /// The statistics are requested in batches of 100 mods with a
+    /// [`ModId::_in`](filters::stats::ModId) filter. Unknown mods are skipped.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use modio::types::id::Id;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let modio = modio::Modio::new("api-key")?;
+    ///
+    /// let mods = modio.game(Id::new(5)).mods();
+    /// for stats in mods.statistics_for(&[Id::new(19), Id::new(20)]).await? {
+    ///     println!("{}: {} downloads", stats.mod_id, stats.downloads_total);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn statistics_for(self, ids: &[ModId]) -> Result<Vec<Statistics>> {
+        use crate::filter::In;
+        use filters::stats::ModId;
+
+        let mut list = Vec::with_capacity(ids.len());
+        for ids in ids.chunks(100) {
+            let stats = self
+                .clone()
+                .statistics(ModId::_in(ids.to_vec()))
+                .collect()
+                .await?;
+            list.extend(stats);
+        }
+        Ok(list)
+    }
+
     /// Returns a `Query` interface to retrieve the event log of all mods of the game sorted by
     /// latest event first.
     ///
@@ -72,6 +181,33 @@ impl Mods {
         let route = Route::GetModsEvents { game_id: self.game };
         Query::new(self.modio, route, filter)
     }
+
+    /// Returns a `Query` interface to retrieve the events of all mods of the game that were
+    /// added after `since`, newest event first.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures_util::TryStreamExt;
+    /// use modio::mods::EventType;
+    /// use modio::types::id::Id;
+    /// # async fn run(since: modio::types::Timestamp) -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let modio = modio::Modio::new("api-key")?;
+    ///
+    /// let mut st = modio.game(Id::new(5)).mods().events_since(since).iter().await?;
+    /// while let Some(event) = st.try_next().await? {
+    ///     if event.event_type == EventType::MODFILE_CHANGED {
+    ///         println!("new modfile for mod {}", event.mod_id);
+    ///     }
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn events_since(self, since: Timestamp) -> Query<Event> {
+        use crate::filter::{Cmp, OrderBy};
+        use filters::events::{DateAdded, Id};
+
+        self.events(DateAdded::gt(since.as_secs()).order_by(Id::desc()))
+    }
 }
 
 /// Reference interface of a mod.
@@ -96,6 +232,31 @@ impl ModRef {
         self.modio.request(route).send().await
     }
 
+    /// Get the mod object together with its direct dependencies.
+    ///
+    /// The dependencies are only requested if [`Mod::dependencies`] is set.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use modio::types::id::Id;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let modio = modio::Modio::new("api-key")?;
+    /// let (mod_, deps) = modio.mod_(Id::new(5), Id::new(19)).get_with_dependencies().await?;
+    ///
+    /// println!("{} has {} dependencies", mod_.name, deps.len());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn get_with_dependencies(self) -> Result<(Mod, Vec<Dependency>)> {
+        let deps = self.dependencies();
+        let mod_ = self.get().await?;
+        if !mod_.dependencies {
+            return Ok((mod_, Vec::new()));
+        }
+        let deps = deps.list().await?;
+        Ok((mod_, deps))
+    }
+
     /// Return a reference to an interface that provides access to the files of a mod.
     pub fn files(&self) -> Files {
         Files::new(self.modio.clone(), self.game, self.id)
@@ -215,12 +376,17 @@ impl ModRef {
             .await
             .map(|_| ())
             .or_else(|err| match (err.status(), err.error_ref()) {
-                (Some(StatusCode::BAD_REQUEST), Some(15028 | 15043)) => Ok(()),
+                (
+                    Some(StatusCode::BAD_REQUEST),
+                    Some(ErrorRef::MOD_RATING_ALREADY_SUBMITTED | ErrorRef::MOD_RATING_NOT_FOUND),
+                ) => Ok(()),
                 _ => Err(err),
             })
     }
 
     /// Subscribe the authenticated user to a mod. [required: token]
+    ///
+    /// Subscribing to a mod the user is already subscribed to is not an error.
     pub async fn subscribe(self) -> Result<()> {
         let route = Route::SubscribeToMod {
             game_id: self.game,
@@ -232,12 +398,14 @@ impl ModRef {
             .await
             .map(|_| ())
             .or_else(|err| match (err.status(), err.error_ref()) {
-                (Some(StatusCode::BAD_REQUEST), Some(15004)) => Ok(()),
+                (Some(StatusCode::BAD_REQUEST), Some(ErrorRef::MOD_ALREADY_SUBSCRIBED)) => Ok(()),
                 _ => Err(err),
             })
     }
 
     /// Unsubscribe the authenticated user from a mod. [required: token]
+    ///
+    /// Unsubscribing from a mod the user is not subscribed to is not an error.
     pub async fn unsubscribe(self) -> Result<()> {
         let route = Route::UnsubscribeFromMod {
             game_id: self.game,
@@ -245,7 +413,7 @@ impl ModRef {
         };
         self.modio.request(route).send().await.or_else(|err| {
             match (err.status(), err.error_ref()) {
-                (Some(StatusCode::BAD_REQUEST), Some(15005)) => Ok(()),
+                (Some(StatusCode::BAD_REQUEST), Some(ErrorRef::MOD_NOT_SUBSCRIBED)) => Ok(()),
                 _ => Err(err),
             }
         })
@@ -258,6 +426,7 @@ pub struct Dependencies {
     modio: Modio,
     game_id: GameId,
     mod_id: ModId,
+    recursive: Option<bool>,
 }
 
 impl Dependencies {
@@ -266,14 +435,23 @@ impl Dependencies {
             modio,
             game_id,
             mod_id,
+            recursive: None,
         }
     }
 
+    /// Include the dependencies of the dependencies.
+    #[must_use]
+    pub fn recursive(mut self, value: bool) -> Self {
+        self.recursive = Some(value);
+        self
+    }
+
     /// List mod dependencies.
     pub async fn list(self) -> Result<Vec<Dependency>> {
         let route = Route::GetModDependencies {
             game_id: self.game_id,
             mod_id: self.mod_id,
+            recursive: self.recursive,
         };
         Query::new(self.modio, route, Filter::default())
             .collect()
@@ -286,11 +464,25 @@ impl Dependencies {
         let route = Route::GetModDependencies {
             game_id: self.game_id,
             mod_id: self.mod_id,
+            recursive: self.recursive,
         };
         let filter = Filter::default();
         Query::new(self.modio, route, filter).iter().await
     }
 
+    /// List all mod dependencies recursively, grouped by their depth in the dependency tree.
+    ///
+    /// The first level contains the direct dependencies of the mod. Installing the levels in
+    /// reverse order installs every mod after its own dependencies.
+    ///
+    /// A mod is only listed once, at the deepest level it was found, and the mod itself is
+    /// skipped if the dependency tree contains a cycle.
+    pub async fn levels(self) -> Result<Vec<Vec<Dependency>>> {
+        let mod_id = self.mod_id;
+        let list = self.recursive(true).list().await?;
+        Ok(group_by_depth(mod_id, list))
+    }
+
     /// Add mod dependencies. [required: token]
     #[allow(clippy::should_implement_trait)]
     pub async fn add(self, options: EditDependenciesOptions) -> Result<()> {
@@ -316,6 +508,19 @@ impl Dependencies {
     }
 }
 
+fn group_by_depth(mod_id: ModId, mut list: Vec<Dependency>) -> Vec<Vec<Dependency>> {
+    let mut seen = HashSet::from([mod_id]);
+    let mut levels = BTreeMap::<u8, Vec<Dependency>>::new();
+
+    list.sort_by_key(|d| std::cmp::Reverse(d.dependency_depth));
+    for dep in list {
+        if seen.insert(dep.mod_id) {
+            levels.entry(dep.dependency_depth).or_default().push(dep);
+        }
+    }
+    levels.into_values().collect()
+}
+
 /// Interface for tags.
 #[derive(Clone)]
 pub struct Tags {
@@ -380,6 +585,50 @@ impl Tags {
     }
 }
 
+/// Parse the game and mod `name_id` from a mod profile url.
+///
+/// The scheme of the url is optional, urls of the test environment are supported as well.
+/// Returns `None` if the url is not a mod profile url.
+///
+/// ```
+/// use modio::parse_mod_url;
+///
+/// let (game, mod_) = parse_mod_url("https://mod.io/g/openxcom/m/the-x-com-files").unwrap();
+/// assert_eq!(game, "openxcom");
+/// assert_eq!(mod_, "the-x-com-files");
+///
+/// assert!(parse_mod_url("https://mod.io/g/openxcom").is_none());
+/// ```
+pub fn parse_mod_url(url: &str) -> Option<(String, String)> {
+    let url = url.trim();
+    let url = match Url::parse(url) {
+        Ok(url) => url,
+        Err(url::ParseError::RelativeUrlWithoutBase) => {
+            Url::parse(&format!("https://{url}")).ok()?
+        }
+        Err(_) => return None,
+    };
+    if !matches!(url.scheme(), "http" | "https") {
+        return None;
+    }
+    let host = url.host_str()?;
+    if host != "mod.io" && !host.ends_with(".mod.io") {
+        return None;
+    }
+    let mut segments = url.path_segments()?;
+    match (
+        segments.next(),
+        segments.next(),
+        segments.next(),
+        segments.next(),
+    ) {
+        (Some("g"), Some(game), Some("m"), Some(mod_)) if !game.is_empty() && !mod_.is_empty() => {
+            Some((game.to_owned(), mod_.to_owned()))
+        }
+        _ => None,
+    }
+}
+
 /// Mod filters & sorting
 ///
 /// # Filters
@@ -577,6 +826,7 @@ pub struct AddModOptions {
     maturity_option: Option<MaturityOption>,
     metadata_blob: Option<String>,
     tags: Option<Vec<String>>,
+    encoding: PercentEncoding,
 }
 
 impl AddModOptions {
@@ -593,10 +843,26 @@ impl AddModOptions {
 
         let logo = FileSource::new_from_file(logo, filename, IMAGE_STAR);
 
+        Self::with_logo_source(name.into(), logo, summary.into())
+    }
+
+    /// Create the options with the logo from memory instead of a file.
+    pub fn with_logo_bytes<T, B, S>(name: T, logo: B, filename: S, summary: T) -> AddModOptions
+    where
+        T: Into<String>,
+        B: Into<Bytes>,
+        S: Into<String>,
+    {
+        let logo = FileSource::new_from_bytes(logo.into(), filename.into(), IMAGE_STAR);
+
+        Self::with_logo_source(name.into(), logo, summary.into())
+    }
+
+    fn with_logo_source(name: String, logo: FileSource, summary: String) -> AddModOptions {
         AddModOptions {
-            name: name.into(),
+            name,
             logo,
-            summary: summary.into(),
+            summary,
             visible: None,
             name_id: None,
             description: None,
@@ -605,6 +871,7 @@ impl AddModOptions {
             maturity_option: None,
             metadata_blob: None,
             tags: None,
+            encoding: PercentEncoding::default(),
         }
     }
 
@@ -634,12 +901,20 @@ impl AddModOptions {
             ..self
         }
     }
+
+    /// Set the percent-encoding of the multipart field names.
+    ///
+    /// See [`PercentEncoding`] for the available modes.
+    #[must_use]
+    pub fn percent_encoding(self, encoding: PercentEncoding) -> Self {
+        Self { encoding, ..self }
+    }
 }
 
 #[doc(hidden)]
 impl From<AddModOptions> for Form {
     fn from(opts: AddModOptions) -> Form {
-        let mut form = Form::new();
+        let mut form = opts.encoding.apply(Form::new());
 
         form = form.text("name", opts.name).text("summary", opts.summary);
 
@@ -792,6 +1067,23 @@ impl AddMediaOptions {
         }
     }
 
+    /// Set the logo from memory instead of a file.
+    #[must_use]
+    pub fn logo_bytes<B, S>(self, logo: B, filename: S) -> Self
+    where
+        B: Into<Bytes>,
+        S: Into<String>,
+    {
+        Self {
+            logo: Some(FileSource::new_from_bytes(
+                logo.into(),
+                filename.into(),
+                IMAGE_STAR,
+            )),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn images_zip<P: AsRef<Path>>(self, images: P) -> Self {
         Self {
@@ -825,6 +1117,23 @@ impl AddMediaOptions {
         }
     }
 
+    /// Add an image from memory to the gallery.
+    #[must_use]
+    pub fn image_bytes<B, S>(self, image: B, filename: S) -> Self
+    where
+        B: Into<Bytes>,
+        S: Into<String>,
+    {
+        let image = FileSource::new_from_bytes(image.into(), filename.into(), IMAGE_STAR);
+        let mut images = self.images.unwrap_or_default();
+        images.push(image);
+
+        Self {
+            images: Some(images),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn youtube(self, urls: &[String]) -> Self {
         Self {
@@ -998,3 +1307,57 @@ impl serde::ser::Serialize for ReorderMediaOptions {
         map.end()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{group_by_depth, parse_mod_url, Dependency};
+    use crate::types::id::Id;
+
+    fn dep(mod_id: u64, depth: u8) -> Dependency {
+        serde_json::from_value(serde_json::json!({
+            "mod_id": mod_id,
+            "date_added": 1_499_841_487,
+            "dependency_depth": depth,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn group_dependencies_by_depth() {
+        let list = vec![dep(2, 0), dep(3, 0), dep(4, 1), dep(3, 1), dep(1, 2)];
+        let levels = group_by_depth(Id::new(1), list);
+
+        let levels = levels
+            .iter()
+            .map(|l| l.iter().map(|d| d.mod_id.get()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(levels, [vec![2], vec![4, 3]]);
+    }
+
+    #[test]
+    fn parse_mod_urls() {
+        let expected = Some(("openxcom".to_owned(), "the-x-com-files".to_owned()));
+        for url in [
+            "https://mod.io/g/openxcom/m/the-x-com-files",
+            "https://mod.io/g/openxcom/m/the-x-com-files/",
+            "https://www.mod.io/g/openxcom/m/the-x-com-files#description",
+            "http://test.mod.io/g/openxcom/m/the-x-com-files?foo=bar",
+            "mod.io/g/openxcom/m/the-x-com-files",
+            " https://mod.io/g/openxcom/m/the-x-com-files\n",
+        ] {
+            assert_eq!(parse_mod_url(url), expected, "{url}");
+        }
+
+        for url in [
+            "https://mod.io/g/openxcom",
+            "https://mod.io/g/openxcom/m/",
+            "https://mod.io/u/xant",
+            "https://example.com/g/openxcom/m/the-x-com-files",
+            "https://notmod.io/g/openxcom/m/the-x-com-files",
+            "ftp://mod.io/g/openxcom/m/the-x-com-files",
+            "",
+        ] {
+            assert_eq!(parse_mod_url(url), None, "{url}");
+        }
+    }
+}
@@ -23,6 +23,7 @@ impl Reports {
     }
 }
 
+/// See the [Submit Report](https://docs.mod.io/#submit-report) docs for more information.
 pub struct Report {
     pub name: String,
     pub contact: Option<String>,
@@ -31,17 +32,27 @@ pub struct Report {
     pub resource: Resource,
 }
 
+/// Type of a report.
 pub enum ReportType {
+    /// Generic report (`0`).
     Generic,
+    /// DMCA report (`1`).
     DMCA,
+    /// Not working (`2`).
     NotWorking,
+    /// Rude content (`3`).
     RudeContent,
+    /// Illegal content (`4`).
     IllegalContent,
+    /// Stolen content (`5`).
     StolenContent,
+    /// False information (`6`).
     FalseInformation,
+    /// Other (`7`).
     Other,
 }
 
+/// Resource that is reported.
 pub enum Resource {
     Game(GameId),
     Mod(ModId),
@@ -105,3 +116,40 @@ impl serde::ser::Serialize for Report {
         map.end()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{assert_ser_tokens, Token};
+
+    use super::{Report, ReportType, Resource};
+    use crate::types::id::Id;
+
+    #[test]
+    fn serialize_report() {
+        let report = Report::new(
+            "Broken mod",
+            None,
+            "Crashes on startup",
+            ReportType::NotWorking,
+            Resource::Mod(Id::new(2)),
+        );
+
+        assert_ser_tokens(
+            &report,
+            &[
+                Token::Map { len: Some(5) },
+                Token::Str("resource"),
+                Token::Str("mods"),
+                Token::Str("id"),
+                Token::U64(2),
+                Token::Str("type"),
+                Token::I32(2),
+                Token::Str("name"),
+                Token::Str("Broken mod"),
+                Token::Str("summary"),
+                Token::Str("Crashes on startup"),
+                Token::MapEnd,
+            ],
+        );
+    }
+}
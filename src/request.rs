@@ -1,7 +1,7 @@
 use futures_util::TryFutureExt;
-use reqwest::header::{HeaderValue, CONTENT_TYPE};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE};
 use reqwest::multipart::Form;
-use reqwest::StatusCode;
+use reqwest::{Method, StatusCode};
 use serde::de::DeserializeOwned;
 use serde::ser::Serialize;
 use tracing::{debug, level_enabled, trace};
@@ -17,15 +17,31 @@ use crate::Modio;
 mod headers {
     const X_MODIO_ERROR_REF: &str = "x-modio-error-ref";
     const X_MODIO_REQUEST_ID: &str = "x-modio-request-id";
+    const X_RATELIMIT_LIMIT: &str = "x-ratelimit-limit";
+    const X_RATELIMIT_REMAINING: &str = "x-ratelimit-remaining";
 
-    use http::header::{HeaderMap, RETRY_AFTER};
+    use std::str::FromStr;
 
-    pub fn retry_after(headers: &HeaderMap) -> Option<u64> {
+    use http::header::{AsHeaderName, HeaderMap, RETRY_AFTER};
+
+    use crate::client::RateLimit;
+
+    fn parse<K: AsHeaderName, T: FromStr>(headers: &HeaderMap, key: K) -> Option<T> {
         headers
-            .get(RETRY_AFTER)
+            .get(key)
             .and_then(|v| v.to_str().ok())
             .and_then(|v| v.parse().ok())
     }
+
+    pub fn retry_after(headers: &HeaderMap) -> Option<u64> {
+        parse(headers, RETRY_AFTER)
+    }
+
+    pub fn rate_limit(headers: &HeaderMap) -> Option<RateLimit> {
+        let limit = parse(headers, X_RATELIMIT_LIMIT)?;
+        let remaining = parse(headers, X_RATELIMIT_REMAINING)?;
+        Some(RateLimit::new(limit, remaining, retry_after(headers)))
+    }
 }
 
 pub struct RequestBuilder {
@@ -35,11 +51,15 @@ pub struct RequestBuilder {
 
 impl RequestBuilder {
     pub fn new(modio: Modio, route: Route) -> Self {
+        Self::from_parts(modio, route.into_parts())
+    }
+
+    fn from_parts(modio: Modio, parts: Parts) -> Self {
         let Parts {
             method,
             path,
             token_required,
-        } = route.into_parts();
+        } = parts;
 
         if let (true, None) = (token_required, &modio.inner.credentials.token) {
             return Self {
@@ -73,6 +93,13 @@ impl RequestBuilder {
         }
     }
 
+    pub fn headers(self, headers: HeaderMap) -> Self {
+        Self {
+            request: self.request.map(|r| r.headers(headers)),
+            ..self
+        }
+    }
+
     pub fn form<T: Serialize + ?Sized>(self, form: &T) -> Self {
         Self {
             request: self.request.map(|r| r.form(form)),
@@ -99,6 +126,10 @@ impl RequestBuilder {
             );
         }
 
+        if let Some(limiter) = &self.modio.inner.rate_limiter {
+            limiter.acquire().await;
+        }
+
         debug!("request: {} {}", req.method(), req.url());
         let response = self
             .modio
@@ -110,6 +141,11 @@ impl RequestBuilder {
 
         let status = response.status();
 
+        if let Some(rate_limit) = headers::rate_limit(response.headers()) {
+            let last = self.modio.inner.rate_limit.lock();
+            *last.unwrap_or_else(|e| e.into_inner()) = Some(rate_limit);
+        }
+
         let retry_after = if status.is_success() {
             None
         } else {
@@ -141,3 +177,66 @@ impl RequestBuilder {
         }
     }
 }
+
+/// A request to a mod.io API endpoint without a dedicated interface.
+///
+/// Constructed with [`Modio::raw_request`]. The request uses the host, the API key and the
+/// access token (if available) of the client like all other requests.
+#[must_use]
+pub struct RawRequest {
+    inner: RequestBuilder,
+}
+
+impl RawRequest {
+    pub(crate) fn new(modio: Modio, method: Method, path: &str) -> Self {
+        let token_required = modio.inner.credentials.token.is_some();
+        let parts = Parts {
+            method,
+            path: path.to_owned(),
+            token_required,
+        };
+        Self {
+            inner: RequestBuilder::from_parts(modio, parts),
+        }
+    }
+
+    /// Add url-encoded query parameters to the request.
+    pub fn query<T: Serialize + ?Sized>(self, query: &T) -> Self {
+        Self {
+            inner: self.inner.query(query),
+        }
+    }
+
+    /// Set an url-encoded form as request body.
+    pub fn form<T: Serialize + ?Sized>(self, form: &T) -> Self {
+        Self {
+            inner: self.inner.form(form),
+        }
+    }
+
+    /// Set a multipart form as request body.
+    pub fn multipart(self, form: Form) -> Self {
+        Self {
+            inner: self.inner.multipart(form),
+        }
+    }
+
+    /// Add a header to the request.
+    pub fn header(self, name: HeaderName, value: HeaderValue) -> Self {
+        let mut headers = HeaderMap::new();
+        headers.insert(name, value);
+        Self {
+            inner: self.inner.headers(headers),
+        }
+    }
+
+    /// Send the request and deserialize the JSON response.
+    ///
+    /// Error responses are returned as [`Error`](crate::Error) like for all other requests.
+    pub async fn send<Out>(self) -> Result<Out>
+    where
+        Out: DeserializeOwned + Send,
+    {
+        self.inner.send().await
+    }
+}
@@ -92,6 +92,7 @@ pub enum Route {
         mod_id: ModId,
         comment_id: CommentId,
     },
+    ExternalAuthApple,
     ExternalAuthDiscord,
     #[allow(dead_code)]
     ExternalAuthEpic,
@@ -125,6 +126,7 @@ pub enum Route {
     },
     GetGameTags {
         game_id: GameId,
+        show_hidden_tags: Option<bool>,
     },
     GetMod {
         game_id: GameId,
@@ -142,6 +144,7 @@ pub enum Route {
     GetModDependencies {
         game_id: GameId,
         mod_id: ModId,
+        recursive: Option<bool>,
     },
     GetModEvents {
         game_id: GameId,
@@ -251,6 +254,7 @@ impl Route {
             | Self::UserFiles
             | Self::UserGames
             | Self::UserMods
+            | Self::UserMuted
             | Self::UserRatings
             | Self::UserSubscriptions => Method::GET,
             Self::AddFile { .. }
@@ -263,6 +267,7 @@ impl Route {
             | Self::AddModMedia { .. }
             | Self::AddModMetadata { .. }
             | Self::AddModTags { .. }
+            | Self::ExternalAuthApple
             | Self::ExternalAuthDiscord
             | Self::ExternalAuthEpic
             | Self::ExternalAuthGoogle
@@ -279,8 +284,7 @@ impl Route {
             | Self::OAuthLogout
             | Self::RateMod { .. }
             | Self::SubmitReport { .. }
-            | Self::SubscribeToMod { .. }
-            | Self::UserMuted => Method::POST,
+            | Self::SubscribeToMod { .. } => Method::POST,
             Self::EditMod { .. }
             | Self::EditModComment { .. }
             | Self::EditFile { .. }
@@ -301,7 +305,8 @@ impl Route {
 
     pub const fn token_required(&self) -> bool {
         match self {
-            Self::ExternalAuthDiscord
+            Self::ExternalAuthApple
+            | Self::ExternalAuthDiscord
             | Self::ExternalAuthEpic
             | Self::ExternalAuthGoogle
             | Self::ExternalAuthMeta
@@ -416,9 +421,7 @@ impl fmt::Display for Route {
             Self::AddGameMedia { game_id } => {
                 path!(f; "/games/", game_id, "/media")
             }
-            Self::AddGameTags { game_id }
-            | Self::DeleteGameTags { game_id }
-            | Self::GetGameTags { game_id } => {
+            Self::AddGameTags { game_id } | Self::DeleteGameTags { game_id } => {
                 path!(f; "/games/", game_id, "/tags")
             }
             Self::AddMod { game_id } | Self::GetMods { game_id } => {
@@ -435,10 +438,25 @@ impl fmt::Display for Route {
                 path!(f; "/games/", game_id, "/mods/", mod_id, "/comments/", comment_id, "/karma")
             }
             Self::AddModDependencies { game_id, mod_id }
-            | Self::DeleteModDependencies { game_id, mod_id }
-            | Self::GetModDependencies { game_id, mod_id } => {
+            | Self::DeleteModDependencies { game_id, mod_id } => {
                 path!(f; "/games/", game_id, "/mods/", mod_id, "/dependencies")
             }
+            Self::GetModDependencies {
+                game_id,
+                mod_id,
+                recursive,
+            } => {
+                f.write_str("/games/")?;
+                fmt::Display::fmt(game_id, f)?;
+                f.write_str("/mods/")?;
+                fmt::Display::fmt(mod_id, f)?;
+                f.write_str("/dependencies")?;
+                if let Some(recursive) = recursive {
+                    f.write_str("?recursive=")?;
+                    fmt::Display::fmt(recursive, f)?;
+                }
+                Ok(())
+            }
             Self::AddModMedia { game_id, mod_id } | Self::DeleteModMedia { game_id, mod_id } => {
                 path!(f; "/games/", game_id, "/mods/", mod_id, "/media")
             }
@@ -491,6 +509,7 @@ impl fmt::Display for Route {
             } => {
                 path!(f; "/games/", game_id, "/mods/", mod_id, "/comments/", comment_id)
             }
+            Self::ExternalAuthApple => f.write_str("/external/appleauth"),
             Self::ExternalAuthDiscord => f.write_str("/external/discordauth"),
             Self::ExternalAuthEpic => f.write_str("/external/epicgamesauth"),
             Self::ExternalAuthGoogle => f.write_str("/external/googleauth"),
@@ -523,6 +542,19 @@ impl fmt::Display for Route {
             Self::GetGameStats { game_id } => {
                 path!(f; "/games/", game_id, "/stats")
             }
+            Self::GetGameTags {
+                game_id,
+                show_hidden_tags,
+            } => {
+                f.write_str("/games/")?;
+                fmt::Display::fmt(game_id, f)?;
+                f.write_str("/tags")?;
+                if let Some(show_hidden_tags) = show_hidden_tags {
+                    f.write_str("?show_hidden_tags=")?;
+                    fmt::Display::fmt(show_hidden_tags, f)?;
+                }
+                Ok(())
+            }
             Self::GetModEvents { game_id, mod_id } => {
                 path!(f; "/games/", game_id, "/mods/", mod_id, "/events")
             }
@@ -791,6 +823,15 @@ mod tests {
         assert_eq!(route.to_string(), "/games/1/mods/2/comments/4");
     }
 
+    #[test]
+    fn external_auth_apple() {
+        let route = Route::ExternalAuthApple;
+
+        assert_eq!(route.to_string(), "/external/appleauth");
+        assert_eq!(route.method(), Method::POST);
+        assert!(!route.token_required());
+    }
+
     #[test]
     fn external_auth_discord() {
         let route = Route::ExternalAuthDiscord;
@@ -905,6 +946,7 @@ mod tests {
         };
 
         assert_eq!(route.to_string(), "/games?show_hidden_tags=true");
+        assert!(!route.token_required());
     }
 
     #[test]
@@ -916,9 +958,19 @@ mod tests {
 
     #[test]
     fn get_game_tags() {
-        let route = Route::GetGameTags { game_id: GAME_ID };
+        let route = Route::GetGameTags {
+            game_id: GAME_ID,
+            show_hidden_tags: None,
+        };
 
         assert_eq!(route.to_string(), "/games/1/tags");
+
+        let route = Route::GetGameTags {
+            game_id: GAME_ID,
+            show_hidden_tags: Some(true),
+        };
+
+        assert_eq!(route.to_string(), "/games/1/tags?show_hidden_tags=true");
     }
 
     #[test]
@@ -957,9 +1009,21 @@ mod tests {
         let route = Route::GetModDependencies {
             game_id: GAME_ID,
             mod_id: MOD_ID,
+            recursive: None,
         };
 
         assert_eq!(route.to_string(), "/games/1/mods/2/dependencies");
+
+        let route = Route::GetModDependencies {
+            game_id: GAME_ID,
+            mod_id: MOD_ID,
+            recursive: Some(true),
+        };
+
+        assert_eq!(
+            route.to_string(),
+            "/games/1/mods/2/dependencies?recursive=true"
+        );
     }
 
     #[test]
@@ -1180,6 +1244,7 @@ mod tests {
         let route = Route::UserMuted;
 
         assert_eq!(route.to_string(), "/me/users/muted");
+        assert_eq!(route.method(), Method::GET);
     }
 
     #[test]
@@ -1,11 +1,11 @@
-use serde_derive::Deserialize;
+use serde_derive::{Deserialize, Serialize};
 use url::Url;
 
 use super::{utils, Timestamp};
 
 /// See the [Access Token Object](https://docs.mod.io/#access-token-object) docs for more
 /// information.
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct AccessToken {
     #[serde(rename = "access_token")]
@@ -15,10 +15,12 @@ pub struct AccessToken {
 }
 
 /// See the [Terms Object](https://docs.mod.io/#terms-object) docs for more information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Terms {
+    /// Terms text in plaintext.
     pub plaintext: String,
+    /// Terms text in HTML.
     pub html: String,
     pub links: Links,
 }
@@ -26,7 +28,7 @@ pub struct Terms {
 /// Part of [`Terms`]
 ///
 /// See the [Terms Object](https://docs.mod.io/#terms-object) docs for more information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Links {
     pub website: Link,
@@ -38,11 +40,57 @@ pub struct Links {
 /// Part of [`Terms`]
 ///
 /// See the [Terms Object](https://docs.mod.io/#terms-object) docs for more information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Link {
     pub text: String,
     #[serde(with = "utils::url")]
     pub url: Url,
+    /// Whether the link must be displayed to the user.
     pub required: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Terms;
+
+    #[test]
+    fn deserialize_terms() {
+        let value = serde_json::json!({
+            "plaintext": "This game uses mod.io to support user-generated content.",
+            "html": "<p>This game uses <a href=\"https://mod.io\">mod.io</a> to support user-generated content.</p>",
+            "links": {
+                "website": {
+                    "text": "mod.io",
+                    "url": "https://mod.io",
+                    "required": false
+                },
+                "terms": {
+                    "text": "Terms of Use",
+                    "url": "https://mod.io/terms",
+                    "required": true
+                },
+                "privacy": {
+                    "text": "Privacy Policy",
+                    "url": "https://mod.io/privacy",
+                    "required": true
+                },
+                "manage": {
+                    "text": "Manage Account",
+                    "url": "https://mod.io/me/account",
+                    "required": false
+                }
+            }
+        });
+        let terms: Terms = serde_json::from_value(value).unwrap();
+
+        assert!(terms.plaintext.starts_with("This game uses mod.io"));
+        assert!(terms.html.starts_with("<p>"));
+        assert_eq!(terms.links.terms.text, "Terms of Use");
+        assert_eq!(terms.links.terms.url.as_str(), "https://mod.io/terms");
+        assert!(terms.links.terms.required);
+        assert!(terms.links.privacy.required);
+        assert!(!terms.links.website.required);
+        assert_eq!(terms.links.manage.url.as_str(), "https://mod.io/me/account");
+    }
+}
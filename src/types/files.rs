@@ -1,7 +1,8 @@
 use std::fmt;
 
 use serde::de::{Deserialize, Deserializer, IgnoredAny, MapAccess, Visitor};
-use serde_derive::Deserialize;
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+use serde_derive::{Deserialize, Serialize};
 use url::Url;
 
 use crate::types::{DeserializeField, MissingField, TargetPlatform};
@@ -28,6 +29,15 @@ pub struct File {
     pub platforms: Vec<Platform>,
 }
 
+impl File {
+    /// Returns `true` if the signed download url has expired at the given time.
+    ///
+    /// Request the file again to get a new download url.
+    pub fn is_download_url_expired(&self, now: Timestamp) -> bool {
+        self.download.date_expires <= now
+    }
+}
+
 impl<'de> Deserialize<'de> for File {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         #[derive(Deserialize)]
@@ -143,11 +153,11 @@ impl<'de> Deserialize<'de> for File {
                     filesize_uncompressed.missing_field("filesize_uncompressed")?;
                 let filehash = filehash.missing_field("filehash")?;
                 let filename = filename.missing_field("filename")?;
-                let version = version.missing_field("version")?;
-                let changelog = changelog.missing_field("changelog")?;
-                let metadata_blob = metadata_blob.missing_field("metadata_blob")?;
+                let version = version.flatten();
+                let changelog = changelog.flatten();
+                let metadata_blob = metadata_blob.flatten();
                 let download = download.missing_field("download")?;
-                let platforms = platforms.missing_field("platforms")?;
+                let platforms = platforms.unwrap_or_default();
 
                 Ok(File {
                     id,
@@ -175,12 +185,36 @@ impl<'de> Deserialize<'de> for File {
     }
 }
 
+impl Serialize for File {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("File", 15)?;
+        s.serialize_field("id", &self.id)?;
+        s.serialize_field("mod_id", &self.mod_id)?;
+        s.serialize_field("date_added", &self.date_added)?;
+        s.serialize_field("date_scanned", &self.virus_scan.date_scanned)?;
+        s.serialize_field("virus_status", &self.virus_scan.status)?;
+        s.serialize_field("virus_positive", &self.virus_scan.result)?;
+        s.serialize_field("filesize", &self.filesize)?;
+        s.serialize_field("filesize_uncompressed", &self.filesize_uncompressed)?;
+        s.serialize_field("filehash", &self.filehash)?;
+        s.serialize_field("filename", &self.filename)?;
+        s.serialize_field("version", &self.version)?;
+        s.serialize_field("changelog", &self.changelog)?;
+        s.serialize_field("metadata_blob", &self.metadata_blob)?;
+        s.serialize_field("download", &self.download)?;
+        s.serialize_field("platforms", &self.platforms)?;
+        s.end()
+    }
+}
+
 /// See the [Modfile Object](https://docs.mod.io/#modfile-object) docs for more information.
 #[derive(Debug)]
 #[non_exhaustive]
 pub struct VirusScan {
     pub date_scanned: Timestamp,
+    /// Status of the virus scan (`virus_status`).
     pub status: VirusStatus,
+    /// Result of the virus scan (`virus_positive`).
     pub result: VirusResult,
 }
 
@@ -204,18 +238,19 @@ newtype_enum! {
 }
 
 /// See the [Filehash Object](https://docs.mod.io/#filehash-object) docs for more information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct FileHash {
     pub md5: String,
 }
 
 /// See the [Download Object](https://docs.mod.io/#download-object) docs for more information.
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Download {
     #[serde(with = "utils::url")]
     pub binary_url: Url,
+    /// Time at which the signed `binary_url` expires.
     pub date_expires: Timestamp,
 }
 
@@ -230,7 +265,7 @@ impl fmt::Debug for Download {
 
 /// See the [Modfile Platform Object](https://docs.mod.io/#modfile-platform-object) docs for more
 /// information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Platform {
     #[serde(rename = "platform")]
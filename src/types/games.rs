@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::fmt;
 
 use serde::de::{Deserialize, Deserializer, IgnoredAny, MapAccess, Visitor};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
 use serde_derive::{Deserialize, Serialize};
 use url::Url;
 
@@ -10,7 +11,7 @@ use super::{deserialize_empty_object, utils, DeserializeField, MissingField};
 use super::{Logo, Status, TargetPlatform, Timestamp};
 
 /// See the [Game Object](https://docs.mod.io/#game-object) docs for more information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Game {
     pub id: GameId,
@@ -22,6 +23,8 @@ pub struct Game {
     pub submission_option: SubmissionOption,
     pub curation_option: CurationOption,
     pub community_options: CommunityOptions,
+    #[serde(default = "RevenueOptions::empty")]
+    pub revenue_options: RevenueOptions,
     pub api_access_options: ApiAccessOptions,
     pub maturity_options: MaturityOptions,
     pub ugc_name: String,
@@ -38,11 +41,12 @@ pub struct Game {
     #[serde(with = "utils::url")]
     pub profile_url: Url,
     /// The field is `None` when the game object is fetched from `/me/games`.
-    #[serde(deserialize_with = "deserialize_empty_object")]
+    #[serde(default, deserialize_with = "deserialize_empty_object")]
     pub stats: Option<Statistics>,
     /// The field is `None` when the game object is fetched from `/me/games`.
-    #[serde(deserialize_with = "deserialize_empty_object")]
+    #[serde(default, deserialize_with = "deserialize_empty_object")]
     pub theme: Option<Theme>,
+    #[serde(default)]
     pub other_urls: Vec<OtherUrl>,
     pub tag_options: Vec<TagOption>,
     pub platforms: Vec<Platform>,
@@ -98,6 +102,18 @@ bitflags! {
         const WEB_EDIT_MODS = 512;
     }
 
+    /// Monetization features enabled for the mods of a game.
+    pub struct RevenueOptions: u8 {
+        /// Allow mods to be sold.
+        const SELL      = 1;
+        /// Allow mods to receive donations.
+        const DONATIONS = 2;
+        /// Allow mods to be traded.
+        const TRADE     = 4;
+        /// Allow mods to control supply and scarcity.
+        const SCARCITY  = 8;
+    }
+
     /// Level of API access allowed by a game.
     pub struct ApiAccessOptions: u8 {
         /// Allow third parties to access a game's API endpoints.
@@ -117,7 +133,7 @@ bitflags! {
 }
 
 /// See the [Icon Object](https://docs.mod.io/#icon-object) docs for more information.
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Icon {
     pub filename: String,
@@ -143,9 +159,22 @@ impl fmt::Debug for Icon {
     }
 }
 
+impl Icon {
+    /// Returns the smallest thumbnail that is at least `width` pixels wide or the original
+    /// image if the thumbnails are smaller.
+    pub fn thumb(&self, width: u32) -> &Url {
+        match width {
+            0..=64 => &self.thumb_64x64,
+            65..=128 => &self.thumb_128x128,
+            129..=256 => &self.thumb_256x256,
+            _ => &self.original,
+        }
+    }
+}
+
 /// See the [Header Image Object](https://docs.mod.io/#header-image-object) docs for more
 /// information.
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct HeaderImage {
     pub filename: String,
@@ -168,9 +197,12 @@ impl fmt::Debug for HeaderImage {
 #[non_exhaustive]
 pub struct Statistics {
     pub game_id: GameId,
+    /// Number of mods of the game (`mods_count_total`).
     pub mods_total: u32,
+    /// Number of subscribers to the mods of the game (`mods_subscribers_total`).
     pub subscribers_total: u32,
     pub downloads: Downloads,
+    /// Time when the statistics are recalculated (`date_expires`).
     pub expired_at: Timestamp,
 }
 
@@ -267,18 +299,38 @@ impl<'de> Deserialize<'de> for Statistics {
     }
 }
 
+impl Serialize for Statistics {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Statistics", 7)?;
+        s.serialize_field("game_id", &self.game_id)?;
+        s.serialize_field("mods_count_total", &self.mods_total)?;
+        s.serialize_field("mods_subscribers_total", &self.subscribers_total)?;
+        s.serialize_field("mods_downloads_total", &self.downloads.total)?;
+        s.serialize_field("mods_downloads_today", &self.downloads.today)?;
+        s.serialize_field(
+            "mods_downloads_daily_average",
+            &self.downloads.daily_average,
+        )?;
+        s.serialize_field("date_expires", &self.expired_at)?;
+        s.end()
+    }
+}
+
 /// Part of [`Statistics`]
 #[derive(Debug)]
 #[non_exhaustive]
 pub struct Downloads {
+    /// Number of all mod downloads (`mods_downloads_total`).
     pub total: u32,
+    /// Number of mod downloads in the last 24 hours (`mods_downloads_today`).
     pub today: u32,
+    /// Average number of mod downloads per day (`mods_downloads_daily_average`).
     pub daily_average: u32,
 }
 
 /// See the [Game Tag Option Object](https://docs.mod.io/#game-tag-option-object) docs for more
 /// information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct TagOption {
     pub name: String,
@@ -310,7 +362,7 @@ impl fmt::Display for TagType {
 }
 
 /// See the [Theme Object](https://docs.mod.io/#theme-object) docs for more information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct Theme {
     pub primary: String,
     pub dark: String,
@@ -321,7 +373,7 @@ pub struct Theme {
 }
 
 /// See the [Game OtherUrls Object](https://docs.mod.io/#game-otherurls-object) docs for more information.
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 pub struct OtherUrl {
     pub label: String,
     #[serde(with = "utils::url")]
@@ -338,7 +390,7 @@ impl fmt::Debug for OtherUrl {
 }
 
 /// See the [Game Platforms Object](https://docs.mod.io/#game-platforms-object) docs for more information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Platform {
     #[serde(rename = "platform")]
@@ -347,3 +399,132 @@ pub struct Platform {
     /// Indicates if users can upload files for this platform.
     pub locked: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{CommunityOptions, CurationOption, Game, MaturityOptions, PresentationOption};
+    use super::{RevenueOptions, Statistics, TagOption, TagType};
+
+    fn game_json() -> serde_json::Value {
+        let page: serde_json::Value =
+            serde_json::from_str(include_str!("../../tests/fixtures/games-page1.json")).unwrap();
+        page["data"][0].clone()
+    }
+
+    #[test]
+    fn deserialize_game_theme_and_other_urls() {
+        let game: Game = serde_json::from_value(game_json()).unwrap();
+
+        let theme = game.theme.expect("theme");
+        assert_eq!(theme.primary, "#0090ff");
+        assert_eq!(theme.danger, "#ff000e");
+
+        assert_eq!(game.other_urls.len(), 1);
+        assert_eq!(game.other_urls[0].label, "Homepage");
+        assert_eq!(game.other_urls[0].url.as_str(), "https://sine.space/");
+
+        let stats = game.stats.expect("stats");
+        assert_eq!(stats.mods_total, 69);
+    }
+
+    #[test]
+    fn deserialize_game_without_optional_objects() {
+        let mut value = game_json();
+        let obj = value.as_object_mut().unwrap();
+        obj.remove("theme");
+        obj.remove("stats");
+        obj.remove("other_urls");
+
+        let game: Game = serde_json::from_value(value).unwrap();
+
+        assert!(game.theme.is_none());
+        assert!(game.stats.is_none());
+        assert!(game.other_urls.is_empty());
+    }
+
+    #[test]
+    fn deserialize_game_options() {
+        let page: serde_json::Value =
+            serde_json::from_str(include_str!("../../tests/fixtures/games-page1.json")).unwrap();
+        let game: Game = serde_json::from_value(page["data"][3].clone()).unwrap();
+
+        assert_eq!(game.ugc_name, "Creations");
+        assert_eq!(game.presentation_option, PresentationOption::GRID_VIEW);
+        assert_eq!(game.curation_option, CurationOption::NO_CURATION);
+        assert_eq!(game.revenue_options, RevenueOptions::DONATIONS);
+        assert_eq!(game.maturity_options, MaturityOptions::ALLOWED);
+    }
+
+    #[test]
+    fn deserialize_game_unknown_options() {
+        let mut value = game_json();
+        let obj = value.as_object_mut().unwrap();
+        obj.remove("revenue_options");
+        obj.insert("curation_option".into(), 9.into());
+        obj.insert("community_options".into(), 0x8001.into());
+
+        let game: Game = serde_json::from_value(value).unwrap();
+
+        assert!(game.revenue_options.is_empty());
+        assert_eq!(game.curation_option.get(), 9);
+        assert!(game
+            .community_options
+            .contains(CommunityOptions::DISCUSSIONS));
+        assert_eq!(game.community_options.bits(), 0x8001);
+    }
+
+    #[test]
+    fn deserialize_game_stats() {
+        let value = serde_json::json!({
+            "game_id": 5,
+            "mods_count_total": 13,
+            "mods_downloads_today": 204,
+            "mods_downloads_total": 27492,
+            "mods_downloads_daily_average": 1230,
+            "mods_subscribers_total": 16394,
+            "date_expires": 1492564103
+        });
+        let stats: Statistics = serde_json::from_value(value).unwrap();
+
+        assert_eq!(stats.game_id, 5);
+        assert_eq!(stats.mods_total, 13);
+        assert_eq!(stats.subscribers_total, 16394);
+        assert_eq!(stats.downloads.total, 27492);
+        assert_eq!(stats.downloads.today, 204);
+        assert_eq!(stats.downloads.daily_average, 1230);
+        assert_eq!(stats.expired_at.as_secs(), 1492564103);
+    }
+
+    #[test]
+    fn deserialize_tag_option() {
+        let value = serde_json::json!({
+            "name": "Game Special Types",
+            "type": "checkboxes",
+            "tags": ["Game", "Tutorial", "Featured"],
+            "tag_count_map": {"Game": 4566, "Tutorial": 4, "Featured": 1},
+            "hidden": true,
+            "locked": true
+        });
+        let option: TagOption = serde_json::from_value(value).unwrap();
+
+        assert_eq!(option.name, "Game Special Types");
+        assert!(matches!(option.kind, TagType::Checkboxes));
+        assert!(option.hidden);
+        assert!(option.locked);
+        assert_eq!(option.tags, ["Game", "Tutorial", "Featured"]);
+        assert_eq!(option.tag_count.get("Game"), Some(&4566));
+    }
+
+    #[test]
+    fn serialize_game_roundtrip() {
+        let game: Game = serde_json::from_value(game_json()).unwrap();
+        let value = serde_json::to_value(&game).unwrap();
+
+        let stats = &value["stats"];
+        assert_eq!(stats["mods_count_total"], 69);
+        assert!(stats.get("downloads").is_none());
+
+        let game: Game = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&game).unwrap(), value);
+    }
+}
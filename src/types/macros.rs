@@ -12,7 +12,9 @@ macro_rules! bitflags {
         $($t:tt)*
     ) => {
         $(#[$outer])*
-        #[derive(Copy, Clone, Eq, PartialEq, Deserialize)]
+        ///
+        /// The names of the enabled flags are listed by [`iter_names`](Self::iter_names).
+        #[derive(Copy, Clone, Eq, PartialEq, Deserialize, Serialize)]
         $vis struct $BitFlags($T);
 
         bitflags::bitflags! {
@@ -70,7 +72,7 @@ macro_rules! newtype_enum {
         $($t:tt)*
     ) => {
         $(#[$outer])*
-        #[derive(Clone, Copy, Eq, Hash, PartialEq, Deserialize)]
+        #[derive(Clone, Copy, Eq, Hash, PartialEq, Deserialize, Serialize)]
         $vis struct $NewtypeEnum($T);
 
         impl $NewtypeEnum {
@@ -160,6 +162,22 @@ macro_rules! newtype_enum {
             }
         }
 
+        impl std::str::FromStr for $NewtypeEnum {
+            type Err = crate::types::ParseValueError;
+
+            /// Parses known values case-insensitively, unknown values are kept as they are.
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $(
+                    if Self::$Variant == s {
+                        return Ok(Self::$Variant);
+                    }
+                )*
+                crate::types::utils::SmallStr::from_str(s)
+                    .map(Self)
+                    .ok_or(crate::types::ParseValueError { max_len: $LENGTH })
+            }
+        }
+
         impl PartialEq<&str> for $NewtypeEnum {
             fn eq(&self, other: &&str) -> bool {
                 self.as_str().eq_ignore_ascii_case(other)
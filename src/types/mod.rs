@@ -1,6 +1,7 @@
 //! Model types defining the mod.io API.
 
 use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use serde::de::{Deserialize, Deserializer};
 use serde_derive::{Deserialize, Serialize};
@@ -21,13 +22,32 @@ use utils::{DeserializeField, MissingField};
 use self::id::{EventId, GameId, ModId, UserId};
 
 /// See the [Message Object](https://docs.mod.io/#message-object) docs for more information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Message {
     pub code: u16,
     pub message: String,
 }
 
+/// Error returned when parsing a string value like [`TargetPlatform`] that exceeds the
+/// maximum length.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseValueError {
+    max_len: usize,
+}
+
+impl fmt::Display for ParseValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "value exceeds the maximum length of {} bytes",
+            self.max_len
+        )
+    }
+}
+
+impl std::error::Error for ParseValueError {}
+
 /// Result type for editing games, mods and files.
 #[derive(Debug, Deserialize)]
 #[serde(untagged, expecting = "edited object or 'no new data' message")]
@@ -50,6 +70,28 @@ pub enum Deletion {
     NoChanges,
 }
 
+impl<T> Editing<T> {
+    /// Returns `true` if the request changed the object.
+    pub fn is_changed(&self) -> bool {
+        matches!(self, Self::Entity(_))
+    }
+
+    /// Returns the edited object or `None` if no new data was submitted.
+    pub fn into_option(self) -> Option<T> {
+        match self {
+            Self::Entity(entity) => Some(entity),
+            Self::NoChanges => None,
+        }
+    }
+}
+
+impl Deletion {
+    /// Returns `true` if the request deleted something.
+    pub fn was_changed(&self) -> bool {
+        matches!(self, Self::Success)
+    }
+}
+
 fn deserialize_message<'de, D>(deserializer: D) -> Result<(), D::Error>
 where
     D: serde::Deserializer<'de>,
@@ -59,7 +101,7 @@ where
 
 /// See the [Multiple Item Response](https://docs.mod.io/#response-formats) docs for more
 /// information.
-#[derive(Debug, PartialEq, Deserialize)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct List<T> {
     pub data: Vec<T>,
@@ -73,6 +115,49 @@ pub struct List<T> {
     pub offset: u32,
 }
 
+impl<T> List<T> {
+    /// Returns `true` if more results exist after this page.
+    pub fn has_more(&self) -> bool {
+        self.offset + self.count < self.total
+    }
+
+    /// Returns the offset of the next page or `None` if this is the last page.
+    pub fn next_offset(&self) -> Option<u32> {
+        self.has_more().then(|| self.offset + self.count)
+    }
+
+    /// Returns an iterator over the items of the list.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+}
+
+impl<T> std::ops::Deref for List<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a List<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
 /// See the [Error Object](https://docs.mod.io/#error-object) docs for more information.
 #[derive(Debug, Deserialize)]
 #[non_exhaustive]
@@ -91,6 +176,46 @@ pub struct Error {
     pub errors: Vec<(String, String)>,
 }
 
+newtype_enum! {
+    /// mod.io's error reference code of an [`Error`].
+    ///
+    /// See the [Error Codes](https://docs.mod.io/#error-codes) docs for more information.
+    pub struct ErrorRef: u16 {
+        /// The API key is missing.
+        const API_KEY_MISSING = 11000;
+        /// The access token is expired or has been revoked.
+        const TOKEN_EXPIRED_OR_REVOKED = 11005;
+        /// The authenticated user account has been deleted.
+        const USER_DELETED = 11006;
+        /// The authenticated user account has been banned.
+        const USER_BANNED = 11007;
+        /// The rate limit of the credentials has been exceeded.
+        const RATE_LIMITED = 11008;
+        /// The rate limit of the credentials for the endpoint has been exceeded.
+        const RATE_LIMITED_ENDPOINT = 11009;
+        /// The security code has already been redeemed.
+        const SECURITY_CODE_ALREADY_REDEEMED = 11011;
+        /// The security code has expired.
+        const SECURITY_CODE_EXPIRED = 11012;
+        /// The security code was requested with a different API key.
+        const SECURITY_CODE_OTHER_API_KEY = 11013;
+        /// The security code is invalid.
+        const SECURITY_CODE_INVALID = 11014;
+        /// The user must accept the Terms of Use before continuing external authorization.
+        const TERMS_ACCEPTANCE_REQUIRED = 11051;
+        /// The authenticated user is already subscribed to the mod.
+        const MOD_ALREADY_SUBSCRIBED = 15004;
+        /// The authenticated user is not subscribed to the mod.
+        const MOD_NOT_SUBSCRIBED = 15005;
+        /// The authenticated user has already submitted this rating for the mod.
+        const MOD_RATING_ALREADY_SUBMITTED = 15028;
+        /// The authenticated user has no rating to remove for the mod.
+        const MOD_RATING_NOT_FOUND = 15043;
+        /// The authenticated user has already submitted this karma for the comment.
+        const COMMENT_KARMA_ALREADY_SUBMITTED = 15059;
+    }
+}
+
 fn deserialize_errors<'de, D: Deserializer<'de>>(
     deserializer: D,
 ) -> Result<Vec<(String, String)>, D::Error> {
@@ -117,7 +242,7 @@ fn deserialize_errors<'de, D: Deserializer<'de>>(
 }
 
 /// See the [User Object](https://docs.mod.io/#user-object) docs for more information.
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct User {
     pub id: UserId,
@@ -144,7 +269,7 @@ impl fmt::Debug for User {
 }
 
 /// See the [Avatar Object](https://docs.mod.io/#avatar-object) docs for more information.
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Avatar {
     pub filename: String,
@@ -167,8 +292,20 @@ impl fmt::Debug for Avatar {
     }
 }
 
+impl Avatar {
+    /// Returns the smallest thumbnail that is at least `width` pixels wide or the original
+    /// image if the thumbnails are smaller.
+    pub fn thumb(&self, width: u32) -> &Url {
+        match width {
+            0..=50 => &self.thumb_50x50,
+            51..=100 => &self.thumb_100x100,
+            _ => &self.original,
+        }
+    }
+}
+
 /// See the [Logo Object](https://docs.mod.io/#logo-object) docs for more information.
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Logo {
     pub filename: String,
@@ -194,6 +331,32 @@ impl fmt::Debug for Logo {
     }
 }
 
+impl Logo {
+    /// Returns the smallest thumbnail that is at least `width` pixels wide or the original
+    /// image if the thumbnails are smaller.
+    ///
+    /// ```
+    /// # let logo: modio::types::Logo = serde_json::from_value(serde_json::json!({
+    /// #     "filename": "logo.png",
+    /// #     "original": "https://example.com/logo.png",
+    /// #     "thumb_320x180": "https://example.com/320x180/logo.png",
+    /// #     "thumb_640x360": "https://example.com/640x360/logo.png",
+    /// #     "thumb_1280x720": "https://example.com/1280x720/logo.png",
+    /// # }))?;
+    /// assert_eq!(logo.thumb(600), &logo.thumb_640x360);
+    /// assert_eq!(logo.thumb(1920), &logo.original);
+    /// # Ok::<_, serde_json::Error>(())
+    /// ```
+    pub fn thumb(&self, width: u32) -> &Url {
+        match width {
+            0..=320 => &self.thumb_320x180,
+            321..=640 => &self.thumb_640x360,
+            641..=1280 => &self.thumb_1280x720,
+            _ => &self.original,
+        }
+    }
+}
+
 newtype_enum! {
     /// See [Status & Visibility](https://docs.mod.io/#status-amp-visibility) docs for more information.
     pub struct Status: u8 {
@@ -220,6 +383,7 @@ newtype_enum! {
     }
 
     /// See the [mod.io docs](https://docs.mod.io/#targeting-a-portal) for more information.
+    #[derive(Deserialize, Serialize)]
     pub struct TargetPortal<12> {
         const STEAM     = b"steam";
         const GOG       = b"gog";
@@ -262,7 +426,7 @@ impl fmt::Display for TargetPlatform {
 }
 
 /// See the [User Event Object](https://docs.mod.io/#user-event-object) docs for more information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Event {
     pub id: EventId,
@@ -275,7 +439,7 @@ pub struct Event {
 
 newtype_enum! {
     /// Type of user event that was triggered.
-    #[derive(Deserialize)]
+    #[derive(Deserialize, Serialize)]
     #[serde(transparent)]
     pub struct EventType<24> {
         /// User has joined a team.
@@ -332,14 +496,55 @@ where
 }
 
 /// Repesentation of a Unix timestamp.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct Timestamp(i64);
 
 impl Timestamp {
+    /// Create a timestamp from the Unix timestamp in seconds.
+    pub const fn from_secs(secs: i64) -> Self {
+        Self(secs)
+    }
+
     /// Get the Unix timestamp in seconds.
     pub const fn as_secs(self) -> i64 {
         self.0
     }
+
+    /// Convert the timestamp to a `SystemTime`.
+    ///
+    /// Returns `None` if the timestamp is out of range for `SystemTime` on the platform.
+    pub fn to_system_time(self) -> Option<SystemTime> {
+        let secs = Duration::from_secs(self.0.unsigned_abs());
+        if self.0 < 0 {
+            UNIX_EPOCH.checked_sub(secs)
+        } else {
+            UNIX_EPOCH.checked_add(secs)
+        }
+    }
+
+    /// Convert the timestamp to a `chrono::DateTime<Utc>`.
+    ///
+    /// Returns `None` if the timestamp is out of range for `DateTime`.
+    #[cfg(feature = "chrono")]
+    pub fn to_chrono(self) -> Option<chrono::DateTime<chrono::Utc>> {
+        chrono::DateTime::from_timestamp(self.0, 0)
+    }
+}
+
+impl From<SystemTime> for Timestamp {
+    fn from(time: SystemTime) -> Self {
+        match time.duration_since(UNIX_EPOCH) {
+            Ok(d) => Self(i64::try_from(d.as_secs()).unwrap_or(i64::MAX)),
+            Err(e) => Self(i64::try_from(e.duration().as_secs()).map_or(i64::MIN, |s| -s)),
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::DateTime<chrono::Utc>> for Timestamp {
+    fn from(time: chrono::DateTime<chrono::Utc>) -> Self {
+        Self(time.timestamp())
+    }
 }
 
 #[cfg(test)]
@@ -347,7 +552,97 @@ mod tests {
     use serde_derive::Deserialize;
     use serde_test::{assert_de_tokens, assert_tokens, Token};
 
-    use super::{deserialize_empty_object, Error, EventType, TargetPlatform};
+    use super::{deserialize_empty_object, Error, EventType, List, TargetPlatform, Timestamp};
+    use super::{Avatar, Deletion, Editing, Status, TargetPortal};
+
+    fn list(count: u32, offset: u32, total: u32) -> List<()> {
+        List {
+            data: vec![(); count as usize],
+            count,
+            total,
+            limit: 100,
+            offset,
+        }
+    }
+
+    #[test]
+    fn list_has_more() {
+        let page = list(100, 0, 250);
+        assert!(page.has_more());
+        assert_eq!(page.next_offset(), Some(100));
+
+        let page = list(100, 100, 250);
+        assert_eq!(page.next_offset(), Some(200));
+    }
+
+    #[test]
+    fn list_iter() {
+        let list = List {
+            data: vec![1, 2, 3],
+            count: 3,
+            total: 3,
+            limit: 100,
+            offset: 0,
+        };
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.first(), Some(&1));
+        assert_eq!(list.iter().sum::<i32>(), 6);
+
+        let mut items = Vec::new();
+        for item in &list {
+            items.push(*item);
+        }
+        assert_eq!(items, list.into_iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn list_last_page() {
+        let page = list(50, 200, 250);
+        assert!(!page.has_more());
+        assert_eq!(page.next_offset(), None);
+
+        let page = list(100, 0, 100);
+        assert!(!page.has_more());
+        assert_eq!(page.next_offset(), None);
+
+        let page = list(0, 0, 0);
+        assert!(!page.has_more());
+        assert_eq!(page.next_offset(), None);
+    }
+
+    #[test]
+    fn timestamp_system_time() {
+        use std::time::{Duration, UNIX_EPOCH};
+
+        let ts = Timestamp::from_secs(1_499_841_487);
+        let time = UNIX_EPOCH + Duration::from_secs(1_499_841_487);
+        assert_eq!(ts.to_system_time(), Some(time));
+        assert_eq!(Timestamp::from(time), ts);
+
+        let ts = Timestamp::from_secs(-10);
+        let time = UNIX_EPOCH - Duration::from_secs(10);
+        assert_eq!(ts.to_system_time(), Some(time));
+        assert_eq!(Timestamp::from(time), ts);
+
+        for ts in [
+            Timestamp::from_secs(i64::MAX),
+            Timestamp::from_secs(i64::MIN),
+        ] {
+            if let Some(time) = ts.to_system_time() {
+                assert_eq!(Timestamp::from(time), ts);
+            }
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn timestamp_chrono() {
+        let ts = Timestamp::from_secs(1_499_841_487);
+        let time = ts.to_chrono().unwrap();
+        assert_eq!(time.timestamp(), 1_499_841_487);
+        assert_eq!(Timestamp::from(time), ts);
+    }
 
     #[test]
     fn deserialize_error_no_errors_field() {
@@ -625,6 +920,67 @@ mod tests {
         assert_eq!("android", TargetPlatform::ANDROID);
     }
 
+    #[test]
+    fn editing_and_deletion_helpers() {
+        let edit: Editing<Status> =
+            serde_json::from_str(r#"{"code":304,"message":"no new data"}"#).unwrap();
+        assert!(!edit.is_changed());
+        assert!(edit.into_option().is_none());
+
+        let edit = Editing::Entity(Status::ACCEPTED);
+        assert!(edit.is_changed());
+        assert_eq!(edit.into_option(), Some(Status::ACCEPTED));
+
+        assert!(Deletion::Success.was_changed());
+        assert!(!Deletion::NoChanges.was_changed());
+    }
+
+    #[test]
+    fn status_unknown_value() {
+        let status: Status = serde_json::from_str("7").unwrap();
+        assert_eq!(status, Status::new(7));
+        assert_eq!(serde_json::to_string(&status).unwrap(), "7");
+        assert_eq!(format!("{status:?}"), "Status(7)");
+
+        #[derive(serde_derive::Deserialize)]
+        struct Object {
+            status: Status,
+        }
+        let value = serde_json::json!({"status": 7});
+        let obj: Object = serde_json::from_value(value).unwrap();
+        assert_eq!(obj.status.get(), 7);
+    }
+
+    #[test]
+    fn target_platform_from_str() {
+        assert_eq!("Windows".parse(), Ok(TargetPlatform::WINDOWS));
+        assert_eq!(
+            "windows".parse::<TargetPlatform>().unwrap().as_str(),
+            "windows"
+        );
+        assert_eq!(
+            "WINDOWS".parse::<TargetPlatform>().unwrap().as_str(),
+            "windows"
+        );
+
+        let platform = "newconsole".parse::<TargetPlatform>().unwrap();
+        assert_eq!(platform.as_str(), "newconsole");
+
+        assert!("a-very-long-platform-name"
+            .parse::<TargetPlatform>()
+            .is_err());
+    }
+
+    #[test]
+    fn target_portal_and_event_type_from_str() {
+        assert_eq!("steam".parse(), Ok(TargetPortal::STEAM));
+        assert_eq!("user_team_join".parse(), Ok(EventType::USER_TEAM_JOIN));
+        assert_eq!(
+            "user_team_join".parse::<EventType>().unwrap().as_str(),
+            "USER_TEAM_JOIN"
+        );
+    }
+
     #[test]
     fn target_platform_serde() {
         assert_tokens(
@@ -637,6 +993,23 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn avatar_thumb() {
+        let avatar: Avatar = serde_json::from_value(serde_json::json!({
+            "filename": "avatar.png",
+            "original": "https://example.com/avatar.png",
+            "thumb_50x50": "https://example.com/50x50/avatar.png",
+            "thumb_100x100": "https://example.com/100x100/avatar.png",
+        }))
+        .unwrap();
+
+        assert_eq!(avatar.thumb(0), &avatar.thumb_50x50);
+        assert_eq!(avatar.thumb(50), &avatar.thumb_50x50);
+        assert_eq!(avatar.thumb(64), &avatar.thumb_100x100);
+        assert_eq!(avatar.thumb(100), &avatar.thumb_100x100);
+        assert_eq!(avatar.thumb(101), &avatar.original);
+    }
 }
 
 // vim: fdm=marker
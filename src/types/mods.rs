@@ -1,8 +1,11 @@
-use std::collections::HashMap;
+use std::borrow::Borrow;
 use std::fmt;
+use std::hash::Hash;
 
+use indexmap::IndexMap;
 use serde::de::{Deserialize, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
-use serde_derive::Deserialize;
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+use serde_derive::{Deserialize, Serialize};
 use url::Url;
 
 use super::files::File;
@@ -11,7 +14,7 @@ use super::{deserialize_empty_object, utils, DeserializeField, MissingField, Tar
 use super::{Logo, Status, Timestamp, User};
 
 /// See the [Mod Object](https://docs.mod.io/#mod-object) docs for more information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Mod {
     pub id: ModId,
@@ -32,7 +35,11 @@ pub struct Mod {
     pub name: String,
     pub name_id: String,
     pub summary: String,
+    /// Detailed description of the mod which allows HTML.
     pub description: Option<String>,
+    /// The `description` field with all HTML tags stripped.
+    ///
+    /// mod.io always returns both fields, there is no request option to select the format.
     pub description_plaintext: Option<String>,
     pub metadata_blob: Option<String>,
     #[serde(with = "utils::url")]
@@ -40,7 +47,7 @@ pub struct Mod {
     #[serde(default, deserialize_with = "deserialize_empty_object")]
     pub modfile: Option<File>,
     pub media: Media,
-    #[serde(rename = "metadata_kvp")]
+    #[serde(rename = "metadata_kvp", serialize_with = "serialize_metadata_kvp")]
     pub metadata: MetadataMap,
     pub tags: Vec<Tag>,
     pub dependencies: bool,
@@ -81,7 +88,7 @@ bitflags! {
 }
 
 /// See the [Mod Event Object](https://docs.mod.io/#mod-event-object) docs for more information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Event {
     pub id: EventId,
@@ -93,7 +100,7 @@ pub struct Event {
 
 newtype_enum! {
     /// Type of mod event that was triggered.
-    #[derive(Deserialize)]
+    #[derive(Deserialize, Serialize)]
     #[serde(transparent)]
     pub struct EventType<24> {
         /// Primary file changed, the mod should be updated.
@@ -123,16 +130,19 @@ impl fmt::Display for EventType {
 
 /// See the [Mod Dependency Object](https://docs.mod.io/#mod-dependencies-object) docs for more
 /// information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Dependency {
     pub mod_id: ModId,
     pub date_added: Timestamp,
+    /// Depth of the dependency in the dependency tree, only set for recursive requests.
+    #[serde(default)]
+    pub dependency_depth: u8,
 }
 
 /// See the [Mod Media Object](https://docs.mod.io/#mod-media-object) docs for more
 /// information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Media {
     #[serde(default = "Vec::new")]
@@ -144,7 +154,7 @@ pub struct Media {
 }
 
 /// See the [Image Object](https://docs.mod.io/#image-object) docs for more information.
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Image {
     pub filename: String,
@@ -164,6 +174,17 @@ impl fmt::Debug for Image {
     }
 }
 
+impl Image {
+    /// Returns the smallest thumbnail that is at least `width` pixels wide or the original
+    /// image if the thumbnails are smaller.
+    pub fn thumb(&self, width: u32) -> &Url {
+        match width {
+            0..=320 => &self.thumb_320x180,
+            _ => &self.original,
+        }
+    }
+}
+
 /// See the [Statistics Object](https://docs.mod.io/#mod-stats-object) docs for more
 /// information.
 #[derive(Debug)]
@@ -318,6 +339,32 @@ impl<'de> Deserialize<'de> for Statistics {
     }
 }
 
+impl Serialize for Statistics {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Statistics", 13)?;
+        s.serialize_field("mod_id", &self.mod_id)?;
+        s.serialize_field("downloads_today", &self.downloads_today)?;
+        s.serialize_field("downloads_total", &self.downloads_total)?;
+        s.serialize_field("subscribers_total", &self.subscribers_total)?;
+        s.serialize_field("popularity_rank_position", &self.popularity.rank_position)?;
+        s.serialize_field("popularity_rank_total_mods", &self.popularity.rank_total)?;
+        s.serialize_field("ratings_total", &self.ratings.total)?;
+        s.serialize_field("ratings_positive", &self.ratings.positive)?;
+        s.serialize_field("ratings_negative", &self.ratings.negative)?;
+        s.serialize_field(
+            "ratings_percentage_positive",
+            &self.ratings.percentage_positive,
+        )?;
+        s.serialize_field(
+            "ratings_weighted_aggregate",
+            &self.ratings.weighted_aggregate,
+        )?;
+        s.serialize_field("ratings_display_text", &self.ratings.display_text)?;
+        s.serialize_field("date_expires", &self.date_expires)?;
+        s.end()
+    }
+}
+
 /// Part of [`Statistics`]
 #[derive(Debug)]
 #[non_exhaustive]
@@ -354,6 +401,22 @@ pub enum Rating {
     },
 }
 
+impl Rating {
+    /// Returns the id of the game of the rated mod.
+    pub fn game_id(&self) -> GameId {
+        match self {
+            Self::Positive { game_id, .. } | Self::Negative { game_id, .. } => *game_id,
+        }
+    }
+
+    /// Returns the id of the rated mod.
+    pub fn mod_id(&self) -> ModId {
+        match self {
+            Self::Positive { mod_id, .. } | Self::Negative { mod_id, .. } => *mod_id,
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for Rating {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -398,8 +461,32 @@ impl<'de> Deserialize<'de> for Rating {
     }
 }
 
+impl Serialize for Rating {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (game_id, mod_id, rating, date_added) = match self {
+            Self::Positive {
+                game_id,
+                mod_id,
+                date_added,
+            } => (game_id, mod_id, 1i8, date_added),
+            Self::Negative {
+                game_id,
+                mod_id,
+                date_added,
+            } => (game_id, mod_id, -1i8, date_added),
+        };
+
+        let mut s = serializer.serialize_struct("Rating", 4)?;
+        s.serialize_field("game_id", game_id)?;
+        s.serialize_field("mod_id", mod_id)?;
+        s.serialize_field("rating", &rating)?;
+        s.serialize_field("date_added", date_added)?;
+        s.end()
+    }
+}
+
 /// See the [Mod Platforms Object](https://docs.mod.io/#mod-platforms-object) docs for more information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Platform {
     #[serde(rename = "platform")]
@@ -411,7 +498,7 @@ pub struct Platform {
 }
 
 /// See the [Mod Tag Object](https://docs.mod.io/#mod-tag-object) docs for more information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Tag {
     pub name: String,
@@ -426,8 +513,10 @@ impl fmt::Display for Tag {
 
 /// See the [Metadata KVP Object](https://docs.mod.io/#metadata-kvp-object) docs for more
 /// information.
+///
+/// The keys are kept in the order they were first returned by the API or inserted.
 #[derive(Debug, Clone, Default, PartialEq)]
-pub struct MetadataMap(HashMap<String, Vec<String>>);
+pub struct MetadataMap(IndexMap<String, Vec<String>>);
 
 impl MetadataMap {
     pub fn new() -> Self {
@@ -435,12 +524,61 @@ impl MetadataMap {
     }
 
     pub fn with_capacity(capacity: usize) -> Self {
-        Self(HashMap::with_capacity(capacity))
+        Self(IndexMap::with_capacity(capacity))
+    }
+
+    /// Removes a key from the map, returning its values if the key was in the map.
+    ///
+    /// The order of the remaining keys is kept.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<Vec<String>>
+    where
+        Q: ?Sized + Hash + Eq,
+        String: Borrow<Q>,
+    {
+        self.0.shift_remove(key)
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for MetadataMap
+where
+    K: Into<String>,
+    V: Into<String>,
+{
+    /// Collects key value pairs, the values of repeated keys are combined.
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for (k, v) in iter {
+            map.entry(k.into()).or_default().push(v.into());
+        }
+        map
+    }
+}
+
+impl<K, V, const N: usize> From<[(K, V); N]> for MetadataMap
+where
+    K: Into<String>,
+    V: Into<String>,
+{
+    fn from(pairs: [(K, V); N]) -> Self {
+        pairs.into_iter().collect()
+    }
+}
+
+impl<K, V> From<&[(K, V)]> for MetadataMap
+where
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    fn from(pairs: &[(K, V)]) -> Self {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.as_ref(), v.as_ref()))
+            .collect()
     }
 }
 
 impl std::ops::Deref for MetadataMap {
-    type Target = HashMap<String, Vec<String>>;
+    type Target = IndexMap<String, Vec<String>>;
 
     fn deref(&self) -> &Self::Target {
         &self.0
@@ -559,23 +697,58 @@ impl<'de> Deserialize<'de> for MetadataMap {
     }
 }
 
+/// Serialize a `MetadataMap` as a sequence of key-value objects in the order of the keys.
+///
+/// This is the inverse of the `Deserialize` impl and is used for the `metadata_kvp` field of
+/// [`Mod`]. The `Serialize` impl of `MetadataMap` itself produces form parameters.
+fn serialize_metadata_kvp<S: Serializer>(
+    map: &MetadataMap,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    #[derive(Serialize)]
+    struct Element<'a> {
+        metakey: &'a str,
+        metavalue: &'a str,
+    }
+
+    let elements = map.iter().flat_map(|(key, values)| {
+        values.iter().map(move |value| Element {
+            metakey: key,
+            metavalue: value,
+        })
+    });
+    serializer.collect_seq(elements)
+}
+
 /// See the [Comment Object](https://docs.mod.io/#comment-object) docs for more information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Comment {
     pub id: CommentId,
     pub resource_id: ResourceId,
     pub user: User,
     pub date_added: Timestamp,
-    pub reply_id: CommentId,
+    /// Id of the parent comment or `None` if the comment is not a reply.
+    #[serde(with = "utils::zero_id")]
+    pub reply_id: Option<CommentId>,
+    /// Position of the comment in its thread, e.g. `"01"`, `"01.01"` or `"01.01.01"`.
     pub thread_position: String,
     pub karma: i32,
     pub content: String,
 }
 
+impl Comment {
+    /// Returns the nesting level of the comment derived from `thread_position`.
+    ///
+    /// Top-level comments have a depth of `0`, replies to them a depth of `1` and so on.
+    pub fn depth(&self) -> usize {
+        self.thread_position.matches('.').count()
+    }
+}
+
 /// See the [Team Member Object](https://docs.mod.io/#team-member-object) docs for more
 /// information.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct TeamMember {
     pub id: MemberId,
@@ -598,9 +771,41 @@ newtype_enum! {
 mod tests {
     use serde_test::{assert_de_tokens, Token};
 
-    use super::{EventType, MetadataMap};
+    use super::{CommunityOptions, EventType, MaturityOption, MetadataMap, Rating};
+    use super::{TeamLevel, Visibility};
     use crate::types::List;
 
+    #[test]
+    fn unknown_numeric_values() {
+        let visibility: Visibility = serde_json::from_str("7").unwrap();
+        assert_eq!(visibility.get(), 7);
+        assert_eq!(serde_json::to_string(&visibility).unwrap(), "7");
+        assert_eq!(format!("{visibility:?}"), "Visibility(7)");
+
+        let level: TeamLevel = serde_json::from_str("42").unwrap();
+        assert_eq!(level.get(), 42);
+        assert_eq!(serde_json::to_string(&level).unwrap(), "42");
+    }
+
+    #[test]
+    fn bitflags_names() {
+        let option = MaturityOption::ALCOHOL | MaturityOption::VIOLENCE;
+        let names = option
+            .iter_names()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["ALCOHOL", "VIOLENCE"]);
+        assert_eq!(format!("{option:?}"), "MaturityOption(ALCOHOL | VIOLENCE)");
+        assert_eq!(option.to_string(), "5");
+
+        let options = CommunityOptions::from_bits_retain(1 | 2048);
+        let names = options
+            .iter_names()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["COMMENTS"]);
+    }
+
     #[test]
     fn metadata_from_result_list_serde() {
         #[derive(Debug, PartialEq, serde_derive::Deserialize)]
@@ -738,4 +943,64 @@ mod tests {
         );
         assert_de_tokens(&EventType::from_bytes(b"foo"), &[Token::Str("foo")]);
     }
+
+    #[test]
+    fn rating_roundtrip() {
+        let value = serde_json::json!({
+            "game_id": 1,
+            "mod_id": 2,
+            "rating": -1,
+            "date_added": 1_499_841_487,
+        });
+        let rating: Rating = serde_json::from_value(value.clone()).unwrap();
+
+        assert!(matches!(rating, Rating::Negative { .. }));
+        assert_eq!(serde_json::to_value(&rating).unwrap(), value);
+    }
+
+    #[test]
+    fn metadata_keeps_key_order() {
+        let value = serde_json::json!([
+            {"metakey": "smg-dmg", "metavalue": "1200"},
+            {"metakey": "pistol-dmg", "metavalue": "800"},
+            {"metakey": "smg-dmg", "metavalue": "1250"},
+            {"metakey": "armor", "metavalue": "50"},
+        ]);
+        let map: MetadataMap = serde_json::from_value(value).unwrap();
+
+        let keys = map.keys().collect::<Vec<_>>();
+        assert_eq!(keys, ["smg-dmg", "pistol-dmg", "armor"]);
+        assert_eq!(map["smg-dmg"], ["1200", "1250"]);
+    }
+
+    #[test]
+    fn metadata_remove_keeps_key_order() {
+        let mut map = MetadataMap::from([("a", "1"), ("b", "2"), ("c", "3")]);
+
+        assert_eq!(map.remove("a"), Some(vec!["1".to_owned()]));
+        assert_eq!(map.remove("x"), None);
+
+        let keys = map.keys().collect::<Vec<_>>();
+        assert_eq!(keys, ["b", "c"]);
+    }
+
+    #[test]
+    fn serialize_metadata_kvp_as_list() {
+        let mut map = MetadataMap::new();
+        map.insert("foo".to_owned(), vec!["bar".to_owned(), "baz".to_owned()]);
+        map.insert("abc".to_owned(), vec!["1".to_owned()]);
+
+        let value = super::serialize_metadata_kvp(&map, serde_json::value::Serializer).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!([
+                {"metakey": "foo", "metavalue": "bar"},
+                {"metakey": "foo", "metavalue": "baz"},
+                {"metakey": "abc", "metavalue": "1"},
+            ])
+        );
+
+        let parsed: MetadataMap = serde_json::from_value(value).unwrap();
+        assert_eq!(parsed, map);
+    }
 }
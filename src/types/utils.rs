@@ -76,10 +76,33 @@ mod smallstr {
 }
 pub use smallstr::SmallStr;
 
+/// (De)serialize an optional id where `0` means no id.
+pub mod zero_id {
+    use serde::de::{Deserialize, Deserializer};
+    use serde::ser::Serializer;
+
+    use crate::types::id::Id;
+
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Option<Id<T>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        u64::deserialize(deserializer).map(Id::new_checked)
+    }
+
+    pub fn serialize<S, T>(id: &Option<Id<T>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u64(id.map_or(0, Id::get))
+    }
+}
+
 pub mod url {
     use std::fmt;
 
     use serde::de::{Deserializer, Error, Visitor};
+    use serde::ser::Serializer;
     use url::Url;
 
     struct UrlVisitor;
@@ -100,10 +123,15 @@ pub mod url {
         deserializer.deserialize_any(UrlVisitor)
     }
 
+    pub fn serialize<S: Serializer>(url: &Url, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(url.as_str())
+    }
+
     pub mod opt {
         use std::fmt;
 
         use serde::de::{Deserializer, Error, Visitor};
+        use serde::ser::Serializer;
         use url::Url;
 
         struct UrlVisitor;
@@ -131,6 +159,13 @@ pub mod url {
         pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Url>, D::Error> {
             d.deserialize_option(UrlVisitor)
         }
+
+        pub fn serialize<S: Serializer>(url: &Option<Url>, s: S) -> Result<S::Ok, S::Error> {
+            match url {
+                Some(url) => s.serialize_some(url.as_str()),
+                None => s.serialize_none(),
+            }
+        }
     }
 }
 
@@ -1,9 +1,12 @@
 //! User interface
+use std::sync::{Arc, MutexGuard};
+
 use crate::prelude::*;
 use crate::types::files::File;
 use crate::types::games::Game;
-use crate::types::id::UserId;
+use crate::types::id::{GameId, ModId, UserId};
 use crate::types::mods::Mod;
+use crate::types::Timestamp;
 
 pub use crate::types::mods::Rating;
 pub use crate::types::{Avatar, User};
@@ -30,6 +33,47 @@ impl Me {
         }
     }
 
+    /// Returns the current user from the client cache or requests it if not cached yet.
+    ///
+    /// The cached user is shared by all clones of the client, but not by clients created with
+    /// [`Modio::with_credentials`] or [`Modio::with_token`]. Use [`Me::refresh`] to update the
+    /// cached user, [`Me::current`] always requests the user without touching the cache.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let modio = modio::Modio::new(("api-key", "token"))?;
+    /// if let Some(user) = modio.user().cached().await? {
+    ///     println!("logged in as {}", user.username);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn cached(self) -> Result<Option<Arc<User>>> {
+        let cached = self.cached_user().clone();
+        match cached {
+            Some(user) => Ok(Some(user)),
+            None => self.refresh().await,
+        }
+    }
+
+    /// Requests the current user and replaces the cached user of the client.
+    ///
+    /// See [`Me::cached`].
+    pub async fn refresh(self) -> Result<Option<Arc<User>>> {
+        let user = self.clone().current().await?.map(Arc::new);
+        *self.cached_user() = user.clone();
+        Ok(user)
+    }
+
+    fn cached_user(&self) -> MutexGuard<'_, Option<Arc<User>>> {
+        self.modio
+            .inner
+            .current_user
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+    }
+
     /// Returns a `Query` interface to retrieve all games the authenticated user added or
     /// is team member of. [required: token]
     ///
@@ -62,6 +106,50 @@ impl Me {
         Query::new(self.modio, Route::UserEvents, filter)
     }
 
+    /// Returns the subscriptions and unsubscriptions of the authenticated user after `since`,
+    /// oldest first. [required: token]
+    ///
+    /// The changes are read from the `USER_SUBSCRIBE` and `USER_UNSUBSCRIBE` events and can
+    /// be applied in order to keep a local list of subscriptions in sync.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn run(since: modio::types::Timestamp) -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let modio = modio::Modio::new(("api-key", "token"))?;
+    /// for change in modio.user().subscription_changes(since).await? {
+    ///     if change.added {
+    ///         println!("subscribed to {}", change.mod_id);
+    ///     } else {
+    ///         println!("unsubscribed from {}", change.mod_id);
+    ///     }
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn subscription_changes(self, since: Timestamp) -> Result<Vec<SubscriptionChange>> {
+        use crate::filter::prelude::*;
+        use filters::events::{DateAdded, EventType as Type};
+
+        let filter = DateAdded::gt(since.as_secs())
+            .and(Type::_in(vec![
+                EventType::USER_SUBSCRIBE,
+                EventType::USER_UNSUBSCRIBE,
+            ]))
+            .order_by(Id::asc());
+
+        let events = self.events(filter).collect().await?;
+        let changes = events
+            .into_iter()
+            .map(|e| SubscriptionChange {
+                game_id: e.game_id,
+                mod_id: e.mod_id,
+                added: e.event_type == EventType::USER_SUBSCRIBE,
+                date_added: e.date_added,
+            })
+            .collect();
+        Ok(changes)
+    }
+
     /// Returns a `Query` interface to retrieve the mods the authenticated user is subscribed to.
     /// [required: token]
     ///
@@ -70,6 +158,32 @@ impl Me {
         Query::new(self.modio, Route::UserSubscriptions, filter)
     }
 
+    /// Returns a `Query` interface to retrieve the mods of a game the authenticated user is
+    /// subscribed to. [required: token]
+    ///
+    /// This is a shortcut for [`Me::subscriptions`] with a `GameId` filter.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures_util::TryStreamExt;
+    /// use modio::types::id::Id;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let modio = modio::Modio::new(("api-key", "token"))?;
+    /// let mut st = modio.user().game_subscriptions(Id::new(5)).iter().await?;
+    /// while let Some(mod_) = st.try_next().await? {
+    ///     println!("{}. {}", mod_.id, mod_.name);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn game_subscriptions(self, game_id: GameId) -> Query<Mod> {
+        use crate::filter::Eq;
+        use filters::subscriptions::GameId as Filter;
+
+        self.subscriptions(Filter::eq(game_id))
+    }
+
     /// Returns a `Query` interface to retrieve the mod ratings submitted by the authenticated user.
     /// [required: token]
     ///
@@ -78,7 +192,83 @@ impl Me {
         Query::new(self.modio, Route::UserRatings, filter)
     }
 
-    /// Get all users muted by the authenticated user. [required: token]
+    /// Returns all mod ratings submitted by the authenticated user together with the rated
+    /// mods. [required: token]
+    ///
+    /// The rated mods are requested in batches per game with an `Id::_in` filter instead of
+    /// one request per rating. Ratings of mods that are no longer available are skipped.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use modio::user::Rating;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let modio = modio::Modio::new(("api-key", "token"))?;
+    /// for (rating, mod_) in modio.user().rated_mods().await? {
+    ///     match rating {
+    ///         Rating::Positive { .. } => println!("you rated {} positively", mod_.name),
+    ///         _ => println!("you rated {} negatively", mod_.name),
+    ///     }
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn rated_mods(self) -> Result<Vec<(Rating, Mod)>> {
+        use std::collections::{BTreeMap, HashMap};
+
+        use crate::filter::In;
+        use crate::mods::filters::Id;
+
+        let ratings = self.clone().ratings(Filter::default()).collect().await?;
+
+        let mut games: BTreeMap<GameId, Vec<ModId>> = BTreeMap::new();
+        for rating in &ratings {
+            games
+                .entry(rating.game_id())
+                .or_default()
+                .push(rating.mod_id());
+        }
+
+        let mut mods = HashMap::new();
+        for (game_id, ids) in games {
+            for ids in ids.chunks(100) {
+                let filter = Id::_in(ids.to_vec());
+                let list = self
+                    .modio
+                    .game(game_id)
+                    .mods()
+                    .search(filter)
+                    .collect()
+                    .await?;
+                mods.extend(list.into_iter().map(|m| (m.id, m)));
+            }
+        }
+
+        let rated = ratings
+            .into_iter()
+            .filter_map(|rating| mods.remove(&rating.mod_id()).map(|m| (rating, m)))
+            .collect();
+        Ok(rated)
+    }
+
+    /// Returns a `Query` interface to retrieve all users muted by the authenticated user.
+    /// [required: token]
+    ///
+    /// mod.io has no endpoint to look up users by name, the `UserId` for [`Me::mute_user`]
+    /// can be taken from objects like [`Comment::user`](crate::types::mods::Comment::user).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures_util::TryStreamExt;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let modio = modio::Modio::new(("api-key", "token"))?;
+    /// let mut st = modio.user().muted_users().iter().await?;
+    /// while let Some(user) = st.try_next().await? {
+    ///     println!("{}: {}", user.id, user.username);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
     pub fn muted_users(self) -> Query<User> {
         Query::new(self.modio, Route::UserMuted, Filter::default())
     }
@@ -101,6 +291,17 @@ impl Me {
     }
 }
 
+/// A subscription change of the authenticated user returned by [`Me::subscription_changes`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SubscriptionChange {
+    pub game_id: GameId,
+    pub mod_id: ModId,
+    /// `true` if the user subscribed to the mod, `false` if the user unsubscribed.
+    pub added: bool,
+    pub date_added: Timestamp,
+}
+
 /// Filters for events, subscriptions and ratings.
 #[rustfmt::skip]
 pub mod filters {
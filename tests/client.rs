@@ -0,0 +1,282 @@
+use httptest::{matchers::*, responders::*};
+use httptest::{Expectation, Server};
+
+use modio::filter::Filter;
+use modio::{Modio, Result, TargetPlatform, TargetPortal};
+
+mod common;
+use common::EMPTY_RESULT;
+
+fn expect_header(name: &'static str, value: &'static str) -> Server {
+    let server = Server::run();
+
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/games"),
+            request::headers(contains((name, value))),
+        ])
+        .respond_with(status_code(200).body(EMPTY_RESULT)),
+    );
+
+    server
+}
+
+#[tokio::test]
+async fn default_user_agent() -> Result<()> {
+    let agent = concat!("modio/", env!("CARGO_PKG_VERSION"));
+    let server = expect_header("user-agent", agent);
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    modio.games().search(Filter::default()).first().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn custom_user_agent() -> Result<()> {
+    let server = expect_header("user-agent", "my-mod-manager/1.0");
+
+    let modio = Modio::builder("foobar")
+        .host(server.url_str("/v1"))
+        .user_agent("my-mod-manager/1.0")
+        .build()?;
+    modio.games().search(Filter::default()).first().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn target_platform() -> Result<()> {
+    let server = expect_header("x-modio-platform", "windows");
+
+    let modio = Modio::builder("foobar")
+        .host(server.url_str("/v1"))
+        .target_platform(TargetPlatform::WINDOWS)
+        .build()?;
+    modio.games().search(Filter::default()).first().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn query_header_overrides_default() -> Result<()> {
+    use modio::lib::header::{HeaderName, HeaderValue};
+
+    let server = expect_header("x-modio-platform", "linux");
+
+    let modio = Modio::builder("foobar")
+        .host(server.url_str("/v1"))
+        .target_platform(TargetPlatform::WINDOWS)
+        .build()?;
+    modio
+        .games()
+        .search(Filter::default())
+        .header(
+            HeaderName::from_static("x-modio-platform"),
+            HeaderValue::from_static("linux"),
+        )
+        .first()
+        .await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn target_portal() -> Result<()> {
+    let server = expect_header("x-modio-portal", "steam");
+
+    let modio = Modio::builder("foobar")
+        .host(server.url_str("/v1"))
+        .target_portal(TargetPortal::STEAM)
+        .build()?;
+    modio.games().search(Filter::default()).first().await?;
+    Ok(())
+}
+
+fn expect_limit(limit: &'static str) -> Server {
+    let server = Server::run();
+
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/games"),
+            request::query(url_decoded(contains(("_limit", limit)))),
+        ])
+        .respond_with(status_code(200).body(EMPTY_RESULT)),
+    );
+
+    server
+}
+
+#[tokio::test]
+async fn default_limit() -> Result<()> {
+    let server = expect_limit("20");
+
+    let modio = Modio::builder("foobar")
+        .host(server.url_str("/v1"))
+        .default_limit(20)
+        .build()?;
+    modio.games().search(Filter::default()).collect().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn default_limit_overridden_by_filter() -> Result<()> {
+    let server = expect_limit("5");
+
+    let modio = Modio::builder("foobar")
+        .host(server.url_str("/v1"))
+        .default_limit(20)
+        .build()?;
+    let filter = Filter::default().limit(5);
+    modio.games().search(filter).collect().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn rate_limit_headers() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(request::path("/v1/games")).respond_with(
+            status_code(200)
+                .insert_header("x-ratelimit-limit", "120")
+                .insert_header("x-ratelimit-remaining", "42")
+                .body(EMPTY_RESULT),
+        ),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    assert!(modio.rate_limit().is_none());
+
+    modio.games().search(Filter::default()).first().await?;
+
+    let rate_limit = modio.rate_limit().expect("rate limit");
+    assert_eq!(rate_limit.limit, 120);
+    assert_eq!(rate_limit.remaining, 42);
+    assert_eq!(rate_limit.retry_after, None);
+    Ok(())
+}
+
+#[tokio::test]
+async fn logout_drops_token() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("POST"),
+            request::path("/v1/oauth/logout"),
+            request::headers(contains(("authorization", "Bearer token"))),
+        ])
+        .respond_with(status_code(200).body(r#"{"code":200,"message":"logged out"}"#)),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), ("foobar", "token"))?;
+    let modio = modio.auth().logout().await?;
+
+    assert!(modio.user().current().await?.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn without_token() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/games"),
+            request::query(url_decoded(contains(("api_key", "foobar")))),
+            request::headers(not(contains(key("authorization")))),
+        ])
+        .respond_with(status_code(200).body(EMPTY_RESULT)),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), ("foobar", "token"))?;
+    let modio = modio.without_token();
+
+    assert!(modio.user().current().await?.is_none());
+    modio.games().search(Filter::default()).first().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn cached_user() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/me"),
+        ])
+        .times(2)
+        .respond_with(status_code(200).body(
+            r#"{"id":1,"name_id":"xant","username":"XanT","date_online":1509922961,"avatar":{},"profile_url":"https://mod.io/u/xant"}"#,
+        )),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), ("foobar", "token"))?;
+
+    let user = modio.user().cached().await?.expect("user");
+    assert_eq!(user.username, "XanT");
+
+    // Served from the cache shared by the clones of the client.
+    let cached = modio.clone().user().cached().await?.expect("user");
+    assert!(std::sync::Arc::ptr_eq(&user, &cached));
+
+    let refreshed = modio.user().refresh().await?.expect("user");
+    assert!(!std::sync::Arc::ptr_eq(&user, &refreshed));
+
+    let cached = modio.user().cached().await?.expect("user");
+    assert!(std::sync::Arc::ptr_eq(&refreshed, &cached));
+    Ok(())
+}
+
+#[tokio::test]
+async fn raw_request() -> Result<()> {
+    use modio::lib::Method;
+
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/me/wallets"),
+            request::query(url_decoded(contains(("api_key", "foobar")))),
+            request::query(url_decoded(contains(("game_id", "5")))),
+            request::headers(contains(("authorization", "Bearer token"))),
+        ])
+        .respond_with(status_code(200).body(r#"{"balance":42}"#)),
+    );
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/missing"),
+        ])
+        .respond_with(
+            status_code(404)
+                .body(r#"{"error":{"code":404,"error_ref":14000,"message":"not found"}}"#),
+        ),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), ("foobar", "token"))?;
+    let value: serde_json::Value = modio
+        .raw_request(Method::GET, "/me/wallets")
+        .query(&[("game_id", "5")])
+        .send()
+        .await?;
+    assert_eq!(value["balance"], 42);
+
+    let err = modio
+        .raw_request(Method::GET, "/missing")
+        .send::<serde_json::Value>()
+        .await
+        .unwrap_err();
+    assert!(err.is_not_found());
+    Ok(())
+}
+
+#[test]
+fn invalid_host() {
+    for host in ["api.mod.io/v1", "ftp://api.mod.io/v1", "https://"] {
+        let err = Modio::builder("foobar").host(host).build().unwrap_err();
+        assert!(err.is_builder(), "{host}");
+    }
+
+    assert!(Modio::builder("foobar")
+        .host("http://localhost:8080/v1")
+        .build()
+        .is_ok());
+}
@@ -0,0 +1,57 @@
+use httptest::{matchers::*, responders::*};
+use httptest::{Expectation, Server};
+use serde_json::Value;
+
+use modio::types::id::Id;
+use modio::{Modio, Result};
+
+mod common;
+use common::{fixture, page};
+
+fn comment(id: u64, reply_id: u64, thread_position: &str) -> Value {
+    let mut comment = fixture("comment.json");
+    comment["id"] = id.into();
+    comment["reply_id"] = reply_id.into();
+    comment["thread_position"] = thread_position.into();
+    comment
+}
+
+#[tokio::test]
+async fn comment_thread() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/games/1/mods/2/comments/3"),
+        ])
+        .respond_with(json_encoded(comment(3, 0, "02"))),
+    );
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/games/1/mods/2/comments"),
+            request::query(url_decoded(contains(("thread_position-lk", "02.*")))),
+        ])
+        .respond_with(status_code(200).body(page(
+            vec![
+                comment(6, 4, "02.01.01"),
+                comment(5, 3, "02.02"),
+                comment(4, 3, "02.01"),
+            ],
+            0,
+            100,
+            3,
+        ))),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let thread = modio
+        .mod_(Id::new(1), Id::new(2))
+        .comments()
+        .thread(Id::new(3))
+        .await?;
+
+    let ids = thread.iter().map(|c| c.id.get()).collect::<Vec<_>>();
+    assert_eq!(ids, [3, 4, 6, 5]);
+    Ok(())
+}
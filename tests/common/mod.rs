@@ -0,0 +1,31 @@
+//! Fixtures shared by the integration tests.
+#![allow(dead_code)]
+
+use serde_json::{json, Value};
+
+pub const EMPTY_RESULT: &str =
+    r#"{"data":[],"result_count":0,"result_offset":0,"result_limit":100,"result_total":0}"#;
+
+/// Parses a JSON file of the `tests/fixtures` directory.
+pub fn fixture(name: &str) -> Value {
+    let path = format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"));
+    let json = std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{path}: {e}"));
+    serde_json::from_str(&json).unwrap()
+}
+
+/// Returns the first mod of `fixtures/mods.json`.
+pub fn mod_fixture() -> Value {
+    fixture("mods.json")["data"][0].take()
+}
+
+/// Returns a result page with the given items.
+pub fn page(data: Vec<Value>, offset: usize, limit: usize, total: usize) -> String {
+    json!({
+        "result_count": data.len(),
+        "data": data,
+        "result_offset": offset,
+        "result_limit": limit,
+        "result_total": total,
+    })
+    .to_string()
+}
@@ -0,0 +1,220 @@
+use httptest::{matchers::*, responders::*};
+use httptest::{Expectation, Server};
+
+use modio::types::files::File;
+use modio::{Modio, Result};
+
+mod common;
+use common::fixture;
+
+fn file(binary_url: String) -> File {
+    let mut file = fixture("file.json");
+    file["download"]["binary_url"] = binary_url.into();
+    serde_json::from_value(file).unwrap()
+}
+
+#[tokio::test]
+async fn save_to_writer() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/files/mod.zip"),
+        ])
+        .respond_with(status_code(200).body("hello world")),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let file = file(server.url_str("/files/mod.zip"));
+
+    let mut buf = Vec::new();
+    modio.download(file).await?.save_to_writer(&mut buf).await?;
+
+    assert_eq!(buf, b"hello world");
+    Ok(())
+}
+
+#[tokio::test]
+async fn save_to_file_buffered() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(request::path("/files/mod.zip"))
+            .respond_with(status_code(200).body("hello world")),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let file = file(server.url_str("/files/mod.zip"));
+    let path = std::env::temp_dir().join(format!("modio-buffered-{}.zip", std::process::id()));
+
+    // The buffer is smaller than the file and flushed multiple times.
+    modio
+        .download(file)
+        .await?
+        .save_to_file_buffered(&path, 4)
+        .await?;
+
+    assert_eq!(std::fs::read(&path).unwrap(), b"hello world");
+    std::fs::remove_file(&path).unwrap();
+    Ok(())
+}
+
+#[tokio::test]
+async fn save_to_file_with_progress() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(request::path("/files/mod.zip"))
+            .respond_with(status_code(200).body("hello world")),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let file = file(server.url_str("/files/mod.zip"));
+    let path = std::env::temp_dir().join(format!("modio-progress-{}.zip", std::process::id()));
+
+    let downloader = modio.download(file).await?;
+    let (download, progress) = downloader.save_to_file_with_progress(&path);
+
+    let start = *progress.borrow();
+    assert_eq!((start.downloaded, start.total), (0, Some(11)));
+
+    tokio::spawn(download).await.unwrap()?;
+
+    let end = *progress.borrow();
+    assert_eq!((end.downloaded, end.total), (11, Some(11)));
+    assert_eq!(std::fs::read(&path).unwrap(), b"hello world");
+    std::fs::remove_file(&path).unwrap();
+    Ok(())
+}
+
+#[tokio::test]
+async fn save_to_file_with_channel() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(request::path("/files/mod.zip"))
+            .respond_with(status_code(200).body("hello world")),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let file = file(server.url_str("/files/mod.zip"));
+    let path = std::env::temp_dir().join(format!("modio-channel-{}.zip", std::process::id()));
+
+    let downloader = modio.download(file).await?;
+    let (task, mut progress) = downloader.save_to_file_with_channel(&path);
+
+    let mut last = None;
+    while let Some(p) = progress.recv().await {
+        last = Some((p.downloaded, p.total));
+    }
+    task.await.unwrap()?;
+
+    assert_eq!(last, Some((11, Some(11))));
+    assert_eq!(std::fs::read(&path).unwrap(), b"hello world");
+    std::fs::remove_file(&path).unwrap();
+    Ok(())
+}
+
+#[tokio::test]
+async fn download_batch() -> Result<()> {
+    use futures_util::StreamExt;
+
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(request::path("/files/a.zip"))
+            .respond_with(status_code(200).body("aaa")),
+    );
+    server.expect(
+        Expectation::matching(request::path("/files/b.zip")).respond_with(status_code(404)),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let dir = std::env::temp_dir();
+    let path_a = dir.join(format!("modio-batch-a-{}.zip", std::process::id()));
+    let path_b = dir.join(format!("modio-batch-b-{}.zip", std::process::id()));
+
+    let downloads = vec![
+        (file(server.url_str("/files/a.zip")), path_a.clone()),
+        (file(server.url_str("/files/b.zip")), path_b.clone()),
+    ];
+    let mut results = modio.download_batch(downloads, 2).collect::<Vec<_>>().await;
+    results.sort_by(|a, b| a.0.cmp(&b.0));
+
+    assert_eq!(results.len(), 2);
+    assert!(results[0].1.is_ok());
+    assert!(results[1].1.is_err());
+    assert_eq!(std::fs::read(&path_a).unwrap(), b"aaa");
+
+    std::fs::remove_file(&path_a).unwrap();
+    Ok(())
+}
+
+#[tokio::test]
+async fn download_if_changed() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::path("/files/mod.zip"),
+            request::headers(not(contains(key("if-none-match")))),
+        ])
+        .respond_with(
+            status_code(200)
+                .insert_header("etag", r#""abc""#)
+                .body("hello world"),
+        ),
+    );
+    server.expect(
+        Expectation::matching(all_of![
+            request::path("/files/mod.zip"),
+            request::headers(contains(("if-none-match", r#""abc""#))),
+        ])
+        .respond_with(status_code(304)),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let url = server.url_str("/files/mod.zip");
+
+    let downloader = modio.download(file(url.clone())).await?;
+    let etag = downloader.etag().expect("etag").to_owned();
+    assert_eq!(etag, r#""abc""#);
+
+    let unchanged = modio.download_if_changed(file(url), &etag).await?;
+    assert!(unchanged.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn download_request_error() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(request::path("/files/mod.zip")).respond_with(status_code(404)),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let err = modio
+        .download(file(server.url_str("/files/mod.zip")))
+        .await
+        .err()
+        .expect("error");
+
+    assert!(err.is_request());
+    assert!(err.download_error().is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn download_url() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/images/logo.png"),
+            request::headers(not(contains(key("authorization")))),
+        ])
+        .respond_with(status_code(200).body("image")),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), ("foobar", "token"))?;
+    let url = server.url_str("/images/logo.png").parse().unwrap();
+    let bytes = modio.download_url(&url).await?.bytes().await?;
+
+    assert_eq!(&bytes[..], b"image");
+    Ok(())
+}
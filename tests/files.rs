@@ -0,0 +1,256 @@
+use httptest::{matchers::*, responders::*};
+use httptest::{Expectation, Server};
+
+use modio::files::AddFileOptions;
+use modio::types::id::Id;
+use modio::{Modio, PercentEncoding, Result, TargetPlatform};
+
+mod common;
+use common::{fixture, mod_fixture, page, EMPTY_RESULT};
+
+#[tokio::test]
+async fn add_file_with_platforms() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("POST"),
+            request::path("/v1/games/1/mods/2/files"),
+            request::body(matches(r#"name="platforms\[\]"\r\n\r\nwindows\r\n"#)),
+            request::body(matches(r#"name="platforms\[\]"\r\n\r\nlinux\r\n"#)),
+        ])
+        .respond_with(status_code(201).body(include_str!("fixtures/file.json"))),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), ("foobar", "token"))?;
+    let options = AddFileOptions::with_read(&b"hello world"[..], "mod.zip")
+        .platforms(&[TargetPlatform::WINDOWS, TargetPlatform::LINUX]);
+
+    let file = modio
+        .mod_(Id::new(1), Id::new(2))
+        .files()
+        .add(options)
+        .await?;
+
+    assert_eq!(file.filename, "mod.zip");
+    Ok(())
+}
+
+#[tokio::test]
+async fn add_file_with_attr_chars_encoding() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("POST"),
+            request::path("/v1/games/1/mods/2/files"),
+            request::body(matches(r"name\*=utf-8''platforms%5B%5D\r\n\r\nwindows\r\n")),
+            request::body(matches(r#"name="filedata"; filename="mod.zip"\r\n"#)),
+        ])
+        .respond_with(status_code(201).body(include_str!("fixtures/file.json"))),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), ("foobar", "token"))?;
+    let options = AddFileOptions::with_read(&b"hello world"[..], "mod.zip")
+        .platforms(&[TargetPlatform::WINDOWS])
+        .percent_encoding(PercentEncoding::AttrChars);
+
+    modio
+        .mod_(Id::new(1), Id::new(2))
+        .files()
+        .add(options)
+        .await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn add_file_with_noop_encoding() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("POST"),
+            request::path("/v1/games/1/mods/2/files"),
+            request::body(matches(r#"name="platforms\[\]"\r\n\r\nwindows\r\n"#)),
+            request::body(not(matches(r"name\*="))),
+        ])
+        .respond_with(status_code(201).body(include_str!("fixtures/file.json"))),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), ("foobar", "token"))?;
+    let options = AddFileOptions::with_read(&b"hello world"[..], "mod.zip")
+        .platforms(&[TargetPlatform::WINDOWS])
+        .percent_encoding(PercentEncoding::NoOp);
+
+    modio
+        .mod_(Id::new(1), Id::new(2))
+        .files()
+        .add(options)
+        .await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn active_only_files() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/games/1/mods/2/files"),
+            request::query(url_decoded(contains(("virus_status", "1")))),
+            request::query(url_decoded(contains(("virus_positive", "0")))),
+        ])
+        .respond_with(status_code(200).body(EMPTY_RESULT)),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let files = modio
+        .mod_(Id::new(1), Id::new(2))
+        .files()
+        .active_only()
+        .collect()
+        .await?;
+
+    assert!(files.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn latest_files() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/games/1/mods/2/files"),
+            request::query(url_decoded(contains(("_sort", "-date_added")))),
+            request::query(url_decoded(contains(("_limit", "1")))),
+        ])
+        .times(1)
+        .respond_with(status_code(200).body(page(vec![fixture("file.json")], 0, 1, 5))),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let files = modio.mod_(Id::new(1), Id::new(2)).files();
+    assert!(files.clone().latest(0).await?.is_empty());
+
+    let files = files.latest(1).await?;
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].filename, "mod.zip");
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_primary_file() -> Result<()> {
+    let mut mod_ = mod_fixture();
+    mod_["modfile"] = fixture("file.json");
+
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/games/1/mods/2"),
+        ])
+        .respond_with(status_code(200).body(mod_.to_string())),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let file = modio
+        .mod_(Id::new(1), Id::new(2))
+        .files()
+        .get_primary()
+        .await?;
+
+    assert_eq!(file.filename, "mod.zip");
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_primary_file_missing() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/games/1/mods/2"),
+        ])
+        .respond_with(status_code(200).body(mod_fixture().to_string())),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let err = modio
+        .mod_(Id::new(1), Id::new(2))
+        .files()
+        .get_primary()
+        .await
+        .unwrap_err();
+
+    assert!(err.is_download());
+    assert!(matches!(
+        err.download_error(),
+        Some(modio::download::Error::NoPrimaryFile { .. })
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn newest_file() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/games/1/mods/2/files"),
+            request::query(url_decoded(contains(("_sort", "-date_added")))),
+            request::query(url_decoded(contains(("_limit", "1")))),
+        ])
+        .respond_with(status_code(200).body(EMPTY_RESULT)),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let file = modio.mod_(Id::new(1), Id::new(2)).files().newest().await?;
+
+    assert!(file.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn latest_active_file_on_later_page() -> Result<()> {
+    use modio::download::ResolvePolicy;
+
+    let file_page = |offset: usize, active: bool| {
+        let mut file = fixture("file.json");
+        if active {
+            file["platforms"][0]["status"] = 1.into();
+        }
+        page(vec![file], offset, 1, 2)
+    };
+
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::path("/v1/games/1/mods/2/files"),
+            request::query(url_decoded(contains(("version", "1.0")))),
+            request::query(url_decoded(not(contains(key("_offset"))))),
+        ])
+        .respond_with(status_code(200).body(file_page(0, false))),
+    );
+    server.expect(
+        Expectation::matching(all_of![
+            request::path("/v1/games/1/mods/2/files"),
+            request::query(url_decoded(contains(("version", "1.0")))),
+            request::query(url_decoded(contains(("_offset", "1")))),
+        ])
+        .respond_with(status_code(200).body(file_page(1, true))),
+    );
+
+    let modio = Modio::builder("foobar")
+        .host(server.url_str("/v1"))
+        .default_limit(1)
+        .build()?;
+    let file = modio
+        .mod_(Id::new(1), Id::new(2))
+        .files()
+        .get_by_version("1.0", ResolvePolicy::LatestActive)
+        .await?;
+
+    assert_eq!(
+        file.platforms[0].status,
+        modio::files::PlatformStatus::APPROVED
+    );
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use httptest::{matchers::*, responders::*};
+use httptest::{Expectation, Server};
+
+use modio::games::EditMediaOptions;
+use modio::mods::AddMediaOptions;
+use modio::types::id::Id;
+use modio::{Modio, Result};
+
+const MESSAGE: &str = r#"{"code":201,"message":"media added"}"#;
+
+#[tokio::test]
+async fn add_mod_media_from_bytes() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("POST"),
+            request::path("/v1/games/1/mods/2/media"),
+            request::body(matches(r#"name="logo"; filename="logo.png"\r\n"#)),
+            request::body(matches(r#"name="image0"; filename="shot.png"\r\n"#)),
+            request::body(matches(r"\r\n\r\nlogo-data\r\n")),
+            request::body(matches(r"\r\n\r\nimage-data\r\n")),
+        ])
+        .respond_with(status_code(201).body(MESSAGE)),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), ("foobar", "token"))?;
+    let options = AddMediaOptions::default()
+        .logo_bytes(&b"logo-data"[..], "logo.png")
+        .image_bytes(b"image-data".to_vec(), "shot.png");
+
+    modio
+        .mod_(Id::new(1), Id::new(2))
+        .add_media(options)
+        .await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn edit_game_media_from_bytes() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("POST"),
+            request::path("/v1/games/1/media"),
+            request::body(matches(r#"name="icon"; filename="icon.png"\r\n"#)),
+            request::body(matches(r"\r\n\r\nicon-data\r\n")),
+        ])
+        .respond_with(status_code(201).body(MESSAGE)),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), ("foobar", "token"))?;
+    let options = EditMediaOptions::default().icon_bytes(&b"icon-data"[..], "icon.png");
+
+    modio.game(Id::new(1)).edit_media(options).await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn file_uploads_have_content_length() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("POST"),
+            request::path("/v1/games/1/mods/2/media"),
+            request::headers(contains(key("content-length"))),
+            request::headers(not(contains(("transfer-encoding", "chunked")))),
+            request::body(matches(r"\r\n\r\nlogo-data\r\n")),
+        ])
+        .respond_with(status_code(201).body(MESSAGE)),
+    );
+
+    let dir = std::env::temp_dir().join(format!("modio-media-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let logo = dir.join("logo.png");
+    std::fs::write(&logo, "logo-data").unwrap();
+
+    let modio = Modio::host(server.url_str("/v1"), ("foobar", "token"))?;
+    let options = AddMediaOptions::default().logo(&logo);
+
+    let result = modio.mod_(Id::new(1), Id::new(2)).add_media(options).await;
+    std::fs::remove_dir_all(&dir).unwrap();
+    result
+}
@@ -0,0 +1,130 @@
+use httptest::{matchers::*, responders::*};
+use httptest::{Expectation, Server};
+
+use modio::types::id::Id;
+use modio::{Modio, Result};
+
+mod common;
+use common::{mod_fixture, page};
+
+#[tokio::test]
+async fn get_with_dependencies() -> Result<()> {
+    let mut mod_ = mod_fixture();
+    mod_["dependencies"] = true.into();
+
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/games/1/mods/2"),
+        ])
+        .respond_with(status_code(200).body(mod_.to_string())),
+    );
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/games/1/mods/2/dependencies"),
+        ])
+        .respond_with(status_code(200).body(
+            r#"{"data":[{"mod_id":3,"date_added":1499841487}],"result_count":1,"result_offset":0,"result_limit":100,"result_total":1}"#,
+        )),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let (mod_, deps) = modio
+        .mod_(Id::new(1), Id::new(2))
+        .get_with_dependencies()
+        .await?;
+
+    assert_eq!(mod_.name, "Rotation Mod");
+    assert_eq!(deps.len(), 1);
+    assert_eq!(deps[0].mod_id, Id::new(3));
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_without_dependencies() -> Result<()> {
+    let mut mod_ = mod_fixture();
+    mod_["dependencies"] = false.into();
+
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/games/1/mods/2"),
+        ])
+        .respond_with(status_code(200).body(mod_.to_string())),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let (_, deps) = modio
+        .mod_(Id::new(1), Id::new(2))
+        .get_with_dependencies()
+        .await?;
+
+    assert!(deps.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_by_name_id() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/games/1/mods"),
+            request::query(url_decoded(contains(("name_id", "rotation-mod")))),
+            request::query(url_decoded(contains(("_limit", "1")))),
+        ])
+        .respond_with(status_code(200).body(include_str!("fixtures/mods.json"))),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let mod_ = modio
+        .game(Id::new(1))
+        .mods()
+        .get_by_name_id("rotation-mod")
+        .await?
+        .expect("mod");
+
+    assert_eq!(mod_.id, Id::new(2));
+    Ok(())
+}
+
+#[tokio::test]
+async fn statistics_for_mods_in_batches() -> Result<()> {
+    let mod_ = mod_fixture();
+    let stats = |id: u64| {
+        let mut stats = mod_["stats"].clone();
+        stats["mod_id"] = id.into();
+        page(vec![stats], 0, 100, 1)
+    };
+    let first = (1..=100).map(|i| i.to_string()).collect::<Vec<_>>();
+    let second = (101..=150).map(|i| i.to_string()).collect::<Vec<_>>();
+
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/games/1/mods/stats"),
+            request::query(url_decoded(contains(("mod_id-in", first.join(","))))),
+        ])
+        .respond_with(status_code(200).body(stats(1))),
+    );
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/games/1/mods/stats"),
+            request::query(url_decoded(contains(("mod_id-in", second.join(","))))),
+        ])
+        .respond_with(status_code(200).body(stats(101))),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let ids = (1..=150).map(Id::new).collect::<Vec<_>>();
+    let stats = modio.game(Id::new(1)).mods().statistics_for(&ids).await?;
+
+    let ids = stats.iter().map(|s| s.mod_id.get()).collect::<Vec<_>>();
+    assert_eq!(ids, [1, 101]);
+    Ok(())
+}
@@ -6,6 +6,9 @@ use modio::filter::prelude::*;
 use modio::types::id::Id;
 use modio::{Modio, Result};
 
+mod common;
+use common::EMPTY_RESULT;
+
 macro_rules! expect_requests {
     ($server:expr, $(query:$query:expr, body:$body:expr),*) => {
         $(
@@ -27,7 +30,7 @@ fn create_empty_result() -> Server {
     expect_requests!(
         server,
         query: any(),
-        body: r#"{"data":[],"result_count":0,"result_offset":0,"result_limit":100,"result_total":0}"#
+        body: EMPTY_RESULT
     );
 
     server
@@ -212,3 +215,109 @@ async fn iter() -> Result<()> {
     assert_eq!((count, None), size_hint);
     Ok(())
 }
+
+#[tokio::test]
+async fn mods_updated_since() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/games/1/mods"),
+            request::query(url_decoded(contains(("date_updated-gt", "1500000000")))),
+            request::query(url_decoded(contains(("_sort", "-date_updated")))),
+        ])
+        .respond_with(status_code(200).body(EMPTY_RESULT)),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let since = modio::types::Timestamp::from_secs(1_500_000_000);
+    let mods = modio
+        .game(Id::new(1))
+        .mods()
+        .updated_since(since)
+        .collect()
+        .await?;
+
+    assert!(mods.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn game_by_name_id() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/games"),
+            request::query(url_decoded(contains(("name_id", "sinespace")))),
+            request::query(url_decoded(contains(("_limit", "1")))),
+        ])
+        .respond_with(status_code(200).body(include_str!("fixtures/games-page1.json"))),
+    );
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/games"),
+            request::query(url_decoded(contains(("name_id", "unknown")))),
+        ])
+        .respond_with(status_code(200).body(EMPTY_RESULT)),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let game = modio.games().get_by_name_id("sinespace").await?;
+    assert_eq!(game.map(|g| g.id), Some(Id::new(2)));
+
+    let game = modio.games().get_by_name_id("unknown").await?;
+    assert!(game.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn games_with_api_key_only() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/games"),
+            request::query(url_decoded(contains(("api_key", "game-key")))),
+            request::headers(not(contains(key("authorization")))),
+        ])
+        .times(2)
+        .respond_with(status_code(200).body(include_str!("fixtures/games-page1.json"))),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "game-key")?;
+    let games = modio.games().search(Filter::default()).first_page().await?;
+    assert!(!games.is_empty());
+
+    let modio = Modio::host(server.url_str("/v1"), ("game-key", "token"))?;
+    modio.games().search(Filter::default()).first_page().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn clone_query() -> Result<()> {
+    // `Eq` of the filter prelude conflicts with the `Eq` matcher of httptest
+    use modio::filter::Eq as _;
+
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/games"),
+            request::query(url_decoded(contains(("name_id", "openxcom")))),
+        ])
+        .times(2)
+        .respond_with(status_code(200).body(include_str!("fixtures/games-page1.json"))),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), "foobar")?;
+    let query = modio.games().search(NameId::eq("openxcom"));
+
+    let first = query.clone().first().await?;
+    let page = query.first_page().await?;
+
+    assert_eq!(first.map(|g| g.id), Some(Id::new(2)));
+    assert_eq!(page.len(), 7);
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use httptest::{matchers::*, responders::*};
+use httptest::{Expectation, Server};
+
+use modio::user::Rating;
+use modio::{Modio, Result};
+
+const RATINGS: &str = r#"{"data":[
+    {"game_id":1,"mod_id":2,"rating":1,"date_added":1499841487},
+    {"game_id":1,"mod_id":4,"rating":1,"date_added":1499841487},
+    {"game_id":1,"mod_id":3,"rating":-1,"date_added":1499841487}
+],"result_count":3,"result_offset":0,"result_limit":100,"result_total":3}"#;
+
+#[tokio::test]
+async fn rated_mods() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/me/ratings"),
+        ])
+        .respond_with(status_code(200).body(RATINGS)),
+    );
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/games/1/mods"),
+            request::query(url_decoded(contains(("id-in", "2,4,3")))),
+        ])
+        .times(1)
+        .respond_with(status_code(200).body(include_str!("fixtures/mods.json"))),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), ("foobar", "token"))?;
+    let rated = modio.user().rated_mods().await?;
+
+    // The mod with id 4 is no longer available.
+    assert_eq!(rated.len(), 2);
+    assert!(matches!(rated[0].0, Rating::Positive { .. }));
+    assert_eq!(rated[0].1.name, "Rotation Mod");
+    assert!(matches!(rated[1].0, Rating::Negative { .. }));
+    assert_eq!(rated[1].1.name, "Balance Mod");
+    Ok(())
+}
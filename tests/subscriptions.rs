@@ -0,0 +1,122 @@
+use httptest::{matchers::*, responders::*};
+use httptest::{Expectation, Server};
+
+use modio::types::id::Id;
+use modio::{Modio, Result};
+
+mod common;
+use common::EMPTY_RESULT;
+
+fn error_response(error_ref: u16) -> String {
+    format!(r#"{{"error":{{"code":400,"error_ref":{error_ref},"message":"error"}}}}"#)
+}
+
+#[tokio::test]
+async fn subscribe_already_subscribed() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("POST"),
+            request::path("/v1/games/1/mods/2/subscribe"),
+        ])
+        .respond_with(status_code(400).body(error_response(15004))),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), ("foobar", "token"))?;
+    modio.mod_(Id::new(1), Id::new(2)).subscribe().await
+}
+
+#[tokio::test]
+async fn unsubscribe_not_subscribed() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("DELETE"),
+            request::path("/v1/games/1/mods/2/subscribe"),
+        ])
+        .respond_with(status_code(400).body(error_response(15005))),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), ("foobar", "token"))?;
+    modio.mod_(Id::new(1), Id::new(2)).unsubscribe().await
+}
+
+#[tokio::test]
+async fn subscribe_other_error() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(request::path("/v1/games/1/mods/2/subscribe"))
+            .respond_with(status_code(400).body(error_response(15000))),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), ("foobar", "token"))?;
+    let err = modio
+        .mod_(Id::new(1), Id::new(2))
+        .subscribe()
+        .await
+        .unwrap_err();
+
+    assert!(err.is_response());
+    Ok(())
+}
+
+#[tokio::test]
+async fn game_subscriptions() -> Result<()> {
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/me/subscribed"),
+            request::query(url_decoded(contains(("game_id", "5")))),
+        ])
+        .respond_with(status_code(200).body(EMPTY_RESULT)),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), ("foobar", "token"))?;
+    let mods = modio
+        .user()
+        .game_subscriptions(Id::new(5))
+        .collect()
+        .await?;
+
+    assert!(mods.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn subscription_changes() -> Result<()> {
+    use modio::types::Timestamp;
+
+    let server = Server::run();
+    server.expect(
+        Expectation::matching(all_of![
+            request::method("GET"),
+            request::path("/v1/me/events"),
+            request::query(url_decoded(contains(("date_added-gt", "1600000000")))),
+            request::query(url_decoded(contains((
+                "event_type-in",
+                "USER_SUBSCRIBE,USER_UNSUBSCRIBE"
+            )))),
+            request::query(url_decoded(contains(("_sort", "id")))),
+        ])
+        .respond_with(status_code(200).body(
+            r#"{"data":[
+                {"id":1,"game_id":1,"mod_id":2,"user_id":1,"date_added":1600000001,"event_type":"USER_SUBSCRIBE"},
+                {"id":2,"game_id":1,"mod_id":2,"user_id":1,"date_added":1600000002,"event_type":"USER_UNSUBSCRIBE"}
+            ],"result_count":2,"result_offset":0,"result_limit":100,"result_total":2}"#,
+        )),
+    );
+
+    let modio = Modio::host(server.url_str("/v1"), ("foobar", "token"))?;
+    let changes = modio
+        .user()
+        .subscription_changes(Timestamp::from_secs(1_600_000_000))
+        .await?;
+
+    assert_eq!(changes.len(), 2);
+    assert_eq!(changes[0].mod_id, Id::new(2));
+    assert!(changes[0].added);
+    assert!(!changes[1].added);
+    assert_eq!(changes[1].date_added, Timestamp::from_secs(1_600_000_002));
+    Ok(())
+}